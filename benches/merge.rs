@@ -1,5 +1,5 @@
 use divan::Bencher;
-use tw_merge::merge::{merge_classes, tw_merge_slice};
+use tw_merge::merge::{merge_classes, merge_cow, merge_into, tw_merge_slice};
 
 fn main() {
     divan::main();
@@ -27,6 +27,77 @@ fn tailwind_merge_slice(bencher: Bencher, len: usize) {
     bencher.with_inputs(|| generate_random_classes(len)).bench_values(|class| tw_merge_slice(&class));
 }
 
+#[divan::bench(
+    args = LENS,
+    sample_count = SAMPLE_COUNT,
+    sample_size = SAMPLE_SIZE
+)]
+fn tailwind_merge_cow(bencher: Bencher, len: usize) {
+    bencher.with_inputs(|| generate_random_classes(len).join(" ")).bench_values(|class| {
+        divan::black_box(merge_cow(class.as_str()));
+    });
+}
+
+// Conflict-free inputs, like static component classes: the input is borrowed.
+#[divan::bench(
+    args = LENS,
+    sample_count = SAMPLE_COUNT,
+    sample_size = SAMPLE_SIZE
+)]
+fn tailwind_merge_cow_unchanged(bencher: Bencher, len: usize) {
+    bencher.with_inputs(|| merge_classes(generate_random_classes(len).join(" "))).bench_values(|class| {
+        divan::black_box(merge_cow(class.as_str()));
+    });
+}
+
+#[divan::bench(
+    args = LENS,
+    sample_count = SAMPLE_COUNT,
+    sample_size = SAMPLE_SIZE
+)]
+fn tailwind_merge_into(bencher: Bencher, len: usize) {
+    let mut buffer = String::new();
+    bencher.with_inputs(|| generate_random_classes(len).join(" ")).bench_local_values(|class| {
+        merge_into(class.as_str(), &mut buffer);
+    });
+}
+
+// The same few inputs merged over and over, like a component rendered on every request.
+#[cfg(feature = "cache")]
+mod repeated {
+    use super::*;
+    use tw_merge::merge::{MergeCache, tw_merge_override};
+
+    const POOL_SIZE: usize = 50;
+
+    fn pool(len: usize) -> Vec<Vec<&'static str>> {
+        (0..POOL_SIZE).map(|_| generate_random_classes(len)).collect()
+    }
+
+    #[divan::bench(
+        args = LENS,
+        sample_count = SAMPLE_COUNT,
+        sample_size = SAMPLE_SIZE
+    )]
+    fn uncached(bencher: Bencher, len: usize) {
+        let pool = pool(len);
+        bencher.with_inputs(|| &pool[fastrand::usize(..POOL_SIZE)]).bench_values(|class| {
+            tw_merge_override(class, Default::default(), |_: &[&str], _: Option<&str>| None, |_: &str| None)
+        });
+    }
+
+    #[divan::bench(
+        args = LENS,
+        sample_count = SAMPLE_COUNT,
+        sample_size = SAMPLE_SIZE
+    )]
+    fn cached(bencher: Bencher, len: usize) {
+        let pool = pool(len);
+        let cache = MergeCache::new(MergeCache::DEFAULT_CAPACITY);
+        bencher.with_inputs(|| &pool[fastrand::usize(..POOL_SIZE)]).bench_values(|class| cache.merge(class));
+    }
+}
+
 // create a vec with the a length of len and fill it with random data
 fn generate_random_classes(n: usize) -> Vec<&'static str> {
     let mut classes_vec = Vec::with_capacity(n);
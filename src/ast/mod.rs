@@ -1,8 +1,14 @@
+mod parsed_class;
 mod parser;
 #[cfg(test)]
 mod parser_test;
+mod parts;
 
-pub(crate) use parser::{parse_tailwind, take_until_unbalanced};
+pub use parsed_class::*;
+#[cfg(test)]
+pub(crate) use parser::parse_tailwind;
+pub(crate) use parser::{parse_single, split_classes, take_until_unbalanced};
+pub(crate) use parts::Parts;
 
 #[derive(Clone, Debug, PartialEq, Default)]
 pub(crate) struct AstStyle<'a> {
@@ -12,17 +18,76 @@ pub(crate) struct AstStyle<'a> {
     /// Is a negative style
     pub negative: bool,
     /// `hover:`, `focus:`, etc.
-    pub variants: Vec<&'a str>,
+    pub variants: Parts<'a>,
     /// parts of style separated by `-`
-    pub elements: Vec<&'a str>,
+    pub elements: Parts<'a>,
     /// Is a arbitrary value
     pub arbitrary: Option<&'a str>,
+    /// v4 CSS variable shorthand, `bg-(--brand)`
+    pub css_variable: Option<CssVariable<'a>>,
+    /// Postfix modifier after `/`, `50` in `bg-red-500/50` or `[0.3]` in `bg-[#fff]/[0.3]`
+    pub modifier: Option<&'a str>,
+}
+
+impl<'a> AstStyle<'a> {
+    /// Elements with the postfix modifier glued back onto the last one.
+    ///
+    /// Fractions such as `aspect-16/9` look like a modifier but are part of the value.
+    /// `None` unless both sides of the `/` are numbers.
+    pub fn elements_with_modifier(&self) -> Option<Parts<'a>> {
+        if self.arbitrary.is_some() || self.css_variable.is_some() {
+            return None;
+        }
+        let (last, modifier) = (self.elements.last()?, self.modifier?);
+        let is_number = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if !is_number(last) || !is_number(modifier) {
+            return None;
+        }
+        let offset = |s: &str| s.as_ptr() as usize - self.source.as_ptr() as usize;
+        let joined = &self.source[offset(last)..offset(modifier) + modifier.len()];
+
+        let mut elements = self.elements.clone();
+        *elements.last_mut()? = joined;
+        Some(elements)
+    }
+}
+
+/// A Tailwind v4 CSS variable shorthand value, e.g. `bg-(--brand)` or `text-(length:--size)`.
+///
+/// <https://tailwindcss.com/docs/adding-custom-styles#using-arbitrary-values>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CssVariable<'a> {
+    /// Optional type hint, `length` in `text-(length:--size)`
+    pub label: Option<&'a str>,
+    /// The variable name including the leading `--`
+    pub name: &'a str,
+}
+
+impl CssVariable<'_> {
+    /// The equivalent arbitrary value, `bg-(--brand)` is the same as `bg-[var(--brand)]`.
+    pub fn to_arbitrary(&self) -> String {
+        let mut arbitrary = String::new();
+        self.write_arbitrary(&mut arbitrary);
+        arbitrary
+    }
+
+    /// Writes the equivalent arbitrary value into `out`, replacing its content but keeping its allocation.
+    pub fn write_arbitrary(&self, out: &mut String) {
+        out.clear();
+        if let Some(label) = self.label {
+            out.push_str(label);
+            out.push(':');
+        }
+        out.push_str("var(");
+        out.push_str(self.name);
+        out.push(')');
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Default)]
 struct AstElements<'a> {
     /// `name-space`
-    pub elements: Vec<&'a str>,
+    pub elements: Parts<'a>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
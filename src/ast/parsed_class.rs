@@ -0,0 +1,144 @@
+use std::ops::Range;
+
+use super::{AstStyle, CssVariable};
+use crate::merge::{CollisionIdFn, CollisionTable, MergeOptions, TwMerger};
+
+/// A single Tailwind class, as interpreted by the merger.
+///
+/// All string slices borrow from the parsed input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParsedClass<'a> {
+    /// The class as it appears in the input.
+    pub source: &'a str,
+    /// Byte range of the class within the parsed input.
+    pub span: Range<usize>,
+    /// Is a `!important` style (`!flex` or `flex!`)
+    pub important: bool,
+    /// Is a negative style (`-mt-2`)
+    pub negative: bool,
+    /// `hover:`, `focus:`, `[&>*]:`, etc. in the order they were written.
+    pub variants: Vec<&'a str>,
+    /// Parts of the style separated by `-` (`bg-red-500` is `["bg", "red", "500"]`)
+    pub elements: Vec<&'a str>,
+    /// Arbitrary value without the brackets (`m-[2px]` is `2px`)
+    pub arbitrary: Option<&'a str>,
+    /// v4 CSS variable shorthand (`bg-(--brand)`)
+    pub css_variable: Option<CssVariable<'a>>,
+    /// Postfix modifier after `/` (`bg-red-500/50` is `50`, `text-sm/[17px]` is `[17px]`)
+    ///
+    /// Fractions are part of the value, `w-1/2` has the elements `["w", "1/2"]` and no modifier.
+    pub modifier: Option<&'a str>,
+}
+
+/// A whitespace separated token that could not be parsed as a Tailwind class.
+///
+/// The merger keeps these untouched in its output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidClass<'a> {
+    /// The token as it appears in the input.
+    pub source: &'a str,
+    /// Byte range of the token within the parsed input.
+    pub span: Range<usize>,
+}
+
+impl<'a> ParsedClass<'a> {
+    fn from_style(
+        style: AstStyle<'a>,
+        span: Range<usize>,
+        table: &CollisionTable,
+        collision_id_fn: &impl CollisionIdFn,
+    ) -> Self {
+        let css_variable = style.css_variable.map(|variable| variable.to_arbitrary());
+        let value = style.arbitrary.or(css_variable.as_deref());
+        // w-1/2: the fraction is part of the value, not a modifier
+        let fraction = table.resolve_class(&style, value, collision_id_fn).ok().and_then(|(_, elements)| elements);
+        let AstStyle { source, important, negative, variants, mut elements, arbitrary, css_variable, mut modifier } =
+            style;
+        if let Some(fraction) = fraction {
+            (elements, modifier) = (fraction, None);
+        }
+        let (variants, elements) = (variants.to_vec(), elements.to_vec());
+        Self { source, span, important, negative, variants, elements, arbitrary, css_variable, modifier }
+    }
+}
+
+/// Parses a single Tailwind class using the global [`MergeOptions`].
+///
+/// Uses the [`TwMerger`] in scope, if any. If you need custom options use [`parse_class_options`].
+///
+/// ```
+/// use tw_merge::ast::parse_class;
+///
+/// let class = parse_class("hover:!-mt-2").unwrap();
+/// assert_eq!(class.variants, vec!["hover"]);
+/// assert_eq!(class.elements, vec!["mt", "2"]);
+/// assert!(class.important && class.negative);
+///
+/// // Fractions are part of the value, opacities are modifiers.
+/// assert_eq!(parse_class("w-1/2").unwrap().elements, vec!["w", "1/2"]);
+/// assert_eq!(parse_class("bg-red-500/50").unwrap().modifier, Some("50"));
+/// ```
+#[inline]
+pub fn parse_class(class: &str) -> Result<ParsedClass<'_>, InvalidClass<'_>> {
+    TwMerger::parse_class_scoped(class).unwrap_or_else(|| parse_class_options(class, Default::default()))
+}
+
+/// Parses a single Tailwind class with the provided options.
+///
+/// The whole input must be one class; surrounding whitespace is not trimmed.
+pub fn parse_class_options(class: &str, options: MergeOptions) -> Result<ParsedClass<'_>, InvalidClass<'_>> {
+    parse_class_override(class, options, CollisionTable::default_ref(), &|_: &[&str], _: Option<&str>| None)
+}
+
+pub(crate) fn parse_class_override<'a>(
+    class: &'a str,
+    options: MergeOptions,
+    table: &CollisionTable,
+    collision_id_fn: &impl CollisionIdFn,
+) -> Result<ParsedClass<'a>, InvalidClass<'a>> {
+    let span = 0..class.len();
+    match super::parser::parse_single(class, &options.into()) {
+        Some(style) => Ok(ParsedClass::from_style(style, span, table, collision_id_fn)),
+        None => Err(InvalidClass { source: class, span }),
+    }
+}
+
+/// Parses every whitespace separated class in the input using the global [`MergeOptions`].
+///
+/// Uses the [`TwMerger`] in scope, if any. If you need custom options use [`parse_classes_options`].
+///
+/// ```
+/// use tw_merge::ast::parse_classes;
+///
+/// let classes = parse_classes("flex  md:p-4");
+/// let spans: Vec<_> = classes.iter().map(|c| c.as_ref().unwrap().span.clone()).collect();
+/// assert_eq!(spans, vec![0..4, 6..12]);
+/// ```
+#[inline]
+pub fn parse_classes(input: &str) -> Vec<Result<ParsedClass<'_>, InvalidClass<'_>>> {
+    TwMerger::parse_classes_scoped(input).unwrap_or_else(|| parse_classes_options(input, Default::default()))
+}
+
+/// Parses every whitespace separated class in the input with the provided options.
+pub fn parse_classes_options(input: &str, options: MergeOptions) -> Vec<Result<ParsedClass<'_>, InvalidClass<'_>>> {
+    parse_classes_override(input, options, CollisionTable::default_ref(), &|_: &[&str], _: Option<&str>| None)
+}
+
+pub(crate) fn parse_classes_override<'a>(
+    input: &'a str,
+    options: MergeOptions,
+    table: &CollisionTable,
+    collision_id_fn: &impl CollisionIdFn,
+) -> Vec<Result<ParsedClass<'a>, InvalidClass<'a>>> {
+    let options = options.into();
+    super::split_classes(&[input])
+        .map(|(start, class)| {
+            let span = start..start + class.len();
+            match super::parser::parse_single(class, &options) {
+                Some(style) => Ok(ParsedClass::from_style(style, span, table, collision_id_fn)),
+                None => Err(InvalidClass { source: class, span }),
+            }
+        })
+        .collect()
+}
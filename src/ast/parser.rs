@@ -2,45 +2,96 @@ use nom::IResult;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_till1, take_while1};
 use nom::character::complete::char;
-use nom::combinator::opt;
-use nom::multi::many0;
-use nom::sequence::{delimited, tuple};
+use nom::combinator::{map, opt, recognize};
+use nom::multi::fold_many0;
+use nom::sequence::{delimited, preceded, tuple};
 
-use super::{ASTVariant, AstElements, AstParseOptions, AstStyle};
+use super::{ASTVariant, AstElements, AstParseOptions, AstStyle, CssVariable, Parts};
 
+#[cfg(test)]
 pub fn parse_tailwind<'a>(class: &[&'a str], options: AstParseOptions<'a>) -> Vec<Result<AstStyle<'a>, &'a str>> {
-    class
-        .iter()
-        .flat_map(|s| s.split_whitespace())
-        .map(|c| match parse_style(c, &options) {
-            Ok(("", style)) => Ok(style),
-            _ => Err(c),
+    split_classes(class).map(|(_, c)| parse_single(c, &options).ok_or(c)).collect()
+}
+
+/// The whitespace separated classes of the strings, with their byte offset in the strings joined by a space.
+pub fn split_classes<'a>(class: &[&'a str]) -> impl Iterator<Item = (usize, &'a str)> {
+    let mut offset = 0;
+    class.iter().flat_map(move |input| {
+        let mut start = offset;
+        offset += input.len() + 1;
+        // Every piece but the last ends with one whitespace char.
+        input.split_inclusive(char::is_whitespace).filter_map(move |piece| {
+            let class = (start, piece.trim_end_matches(char::is_whitespace));
+            start += piece.len();
+            Some(class).filter(|(_, class)| !class.is_empty())
         })
-        .collect()
+    })
+}
+
+/// Parses a single class, failing unless the whole input is consumed.
+#[inline]
+pub fn parse_single<'a>(class: &'a str, options: &AstParseOptions<'a>) -> Option<AstStyle<'a>> {
+    match parse_style(class, options) {
+        Ok(("", style)) => Some(style),
+        _ => None,
+    }
 }
 
 #[inline]
 fn parse_style<'a>(input: &'a str, options: &AstParseOptions<'a>) -> IResult<&'a str, AstStyle<'a>> {
     // v4 supports ! at end (flex!), v3 supports ! at start (!flex)
-    let (rest, (variants, important_prefix, negative, elements, arbitrary, important_suffix)) = tuple((
-        many0(|s| parse_variant(options.separator, s)),
+    let (rest, (variants, important_prefix, negative, elements)) = tuple((
+        fold_many0(
+            |s| parse_variant(options.separator, s),
+            Parts::default,
+            |mut variants, variant| {
+                variants.push(match variant {
+                    ASTVariant::Normal(v) => v,
+                    ASTVariant::DataAttribute(v) => v,
+                    ASTVariant::ArbitraryAttribute(v) => v,
+                });
+                variants
+            },
+        ),
         opt(char('!')),
         opt(char('-')),
         opt(|s| parse_elements(options.prefix, s)),
-        opt(parse_arbitrary),
-        opt(char('!')), // v4: important at end
     ))(input)?;
+    let mut elements = elements.unwrap_or_default().elements;
 
-    let source = &input[..input.len() - rest.len()];
+    let (rest, (value, mut modifier)) = match elements.last_mut() {
+        // text-sm/[17px]: the bracket belongs to the modifier, not the value
+        Some(last) if last.len() > 1 && last.ends_with('/') => {
+            *last = &last[..last.len() - 1];
+            let (rest, modifier) = parse_modifier_value(rest)?;
+            (rest, (None, Some(modifier)))
+        }
+        _ => tuple((
+            // v3 arbitrary value `[...]` or v4 CSS variable shorthand `(...)`
+            opt(alt((
+                map(parse_arbitrary, |arbitrary| (Some(arbitrary), None)),
+                map(parse_css_variable, |variable| (None, Some(variable))),
+            ))),
+            // bg-[#fff]/[0.3], bg-(--brand)/50
+            opt(preceded(char('/'), parse_modifier_value)),
+        ))(rest)?,
+    };
+    let (arbitrary, css_variable) = value.unwrap_or_default();
 
-    let variants = variants
-        .into_iter()
-        .map(|v| match v {
-            ASTVariant::Normal(v) => v,
-            ASTVariant::DataAttribute(v) => v,
-            ASTVariant::ArbitraryAttribute(v) => v,
-        })
-        .collect();
+    // bg-red-500/50, text-lg/7
+    if modifier.is_none()
+        && arbitrary.is_none()
+        && css_variable.is_none()
+        && let Some(last) = elements.last_mut()
+        && let Some((base, value)) = last.rsplit_once('/').filter(|(b, v)| !b.is_empty() && !v.is_empty())
+    {
+        *last = base;
+        modifier = Some(value);
+    }
+
+    let (rest, important_suffix) = opt(char('!'))(rest)?; // v4: important at end
+
+    let source = &input[..input.len() - rest.len()];
 
     Ok((
         rest,
@@ -49,8 +100,10 @@ fn parse_style<'a>(input: &'a str, options: &AstParseOptions<'a>) -> IResult<&'a
             important: important_prefix.is_some() || important_suffix.is_some(),
             negative: negative.is_some(),
             variants,
-            elements: elements.unwrap_or_default().elements,
+            elements,
             arbitrary,
+            css_variable,
+            modifier,
         },
     ))
 }
@@ -71,10 +124,20 @@ fn parse_elements<'a>(prefix: &'a str, input: &'a str) -> IResult<&'a str, AstEl
         Ok((rest, out))
     }
 
-    let (rest, (_, first, other)) = tuple((tag(prefix), parse_head, many0(parse_rest)))(input)?;
-    let mut out = vec![first];
-    out.extend(other);
-    Ok((rest, AstElements { elements: out }))
+    let (rest, (_, first)) = tuple((tag(prefix), parse_head))(input)?;
+    let (rest, elements) = fold_many0(
+        parse_rest,
+        move || {
+            let mut elements = Parts::default();
+            elements.push(first);
+            elements
+        },
+        |mut elements, element| {
+            elements.push(element);
+            elements
+        },
+    )(rest)?;
+    Ok((rest, AstElements { elements }))
 }
 
 #[inline]
@@ -124,6 +187,40 @@ fn parse_arbitrary(input: &str) -> IResult<&str, &str> {
     Ok((rest, arbitrary))
 }
 
+// Postfix modifier after `/`: opacity (bg-red-500/50) or line-height (text-lg/7)
+// https://tailwindcss.com/docs/background-color#changing-the-opacity
+// https://tailwindcss.com/docs/font-size#setting-the-line-height
+#[inline]
+fn parse_modifier_value(input: &str) -> IResult<&str, &str> {
+    alt((
+        recognize(delimited(tag("["), take_until_unbalanced('[', ']'), tag("]"))),
+        recognize(delimited(tag("("), take_until_unbalanced('(', ')'), tag(")"))),
+        take_till1(|c: char| matches!(c, ' ' | '\n' | '\r' | '[' | ']' | '(' | ')' | '!' | '/')),
+    ))(input)
+}
+
+// v4: bg-(--brand), text-(length:--size)
+// https://tailwindcss.com/docs/adding-custom-styles#using-arbitrary-values
+#[inline]
+fn parse_css_variable(input: &str) -> IResult<&str, CssVariable<'_>> {
+    let parser = delimited(tag("("), take_until_unbalanced('(', ')'), tag(")"));
+    let (rest, (_, inner)) = tuple((opt(char('-')), parser))(input)?;
+
+    let (label, name) = match inner.split_once(':') {
+        Some((label, name)) if !label.is_empty() && label.chars().all(|c| c.is_ascii_lowercase() || c == '-') => {
+            (Some(label), name)
+        }
+        _ => (None, inner),
+    };
+
+    if !name.starts_with("--") {
+        let error = nom::error::Error::new(input, nom::error::ErrorKind::Verify);
+        return Err(nom::Err::Error(error));
+    }
+
+    Ok((rest, CssVariable { label, name }))
+}
+
 // https://stackoverflow.com/questions/70630556/parse-allowing-nested-parentheses-in-nom
 pub fn take_until_unbalanced(opening_bracket: char, closing_bracket: char) -> impl Fn(&str) -> IResult<&str, &str> {
     move |i: &str| {
@@ -136,9 +233,11 @@ pub fn take_until_unbalanced(opening_bracket: char, closing_bracket: char) -> im
                 '\\' => {
                     // Skip the escape char `\`.
                     index += '\\'.len_utf8();
-                    // Skip also the following char.
-                    let c = it.next().unwrap_or_default();
-                    index += c.len_utf8();
+                    // Skip also the following char, a trailing `\` escapes nothing.
+                    match it.next() {
+                        Some(c) => index += c.len_utf8(),
+                        None => break,
+                    }
                 }
                 c if c == opening_bracket => {
                     bracket_counter += 1;
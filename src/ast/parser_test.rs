@@ -1,7 +1,10 @@
 use nom::multi::many0;
 
-use super::parser::{parse_arbitrary_attribute_variant, parse_data_attribute_variant, parse_variant};
-use super::{ASTVariant, AstParseOptions, AstStyle};
+use super::parser::{
+    parse_arbitrary_attribute_variant, parse_data_attribute_variant, parse_variant, split_classes,
+    take_until_unbalanced,
+};
+use super::{ASTVariant, AstParseOptions, AstStyle, CssVariable};
 
 fn parse_tailwind<'a>(class: &'a str) -> Vec<Result<AstStyle<'a>, &'a str>> {
     let options = AstParseOptions::default();
@@ -18,25 +21,31 @@ fn basic_parse() {
             source: "flex",
             important: false,
             negative: false,
-            variants: vec![],
-            elements: vec!["flex"],
+            variants: vec![].into(),
+            elements: vec!["flex"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
         Ok(AstStyle {
             source: "justify-between",
             important: false,
             negative: false,
-            variants: vec![],
-            elements: vec!["justify", "between"],
+            variants: vec![].into(),
+            elements: vec!["justify", "between"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
         Ok(AstStyle {
             source: "items-center",
             important: false,
             negative: false,
-            variants: vec![],
-            elements: vec!["items", "center"],
+            variants: vec![].into(),
+            elements: vec!["items", "center"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
     ];
 
@@ -53,9 +62,11 @@ fn test_with_options() {
         source: "dark|hover|tw-flex",
         important: false,
         negative: false,
-        variants: vec!["dark", "hover"],
-        elements: vec!["flex"],
+        variants: vec!["dark", "hover"].into(),
+        elements: vec!["flex"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
 
     assert_eq!(result, expected)
@@ -69,9 +80,11 @@ fn parse_with_negative() {
         source: "-my-2",
         important: false,
         negative: true,
-        variants: vec![],
-        elements: vec!["my", "2"],
+        variants: vec![].into(),
+        elements: vec!["my", "2"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -84,9 +97,11 @@ fn test_with_important() {
         source: "!bg-blue-500",
         important: true,
         negative: false,
-        variants: vec![],
-        elements: vec!["bg", "blue", "500"],
+        variants: vec![].into(),
+        elements: vec!["bg", "blue", "500"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -100,9 +115,11 @@ fn test_with_important_v4_suffix() {
         source: "bg-blue-500!",
         important: true,
         negative: false,
-        variants: vec![],
-        elements: vec!["bg", "blue", "500"],
+        variants: vec![].into(),
+        elements: vec!["bg", "blue", "500"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -115,9 +132,11 @@ fn multiple_variants() {
         source: "hover:md:flex",
         important: false,
         negative: false,
-        variants: vec!["hover", "md"],
-        elements: vec!["flex"],
+        variants: vec!["hover", "md"].into(),
+        elements: vec!["flex"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -130,9 +149,11 @@ fn aria_attributes() {
         source: "aria-checked:true",
         important: false,
         negative: false,
-        variants: vec!["aria-checked"],
-        elements: vec!["true"],
+        variants: vec!["aria-checked"].into(),
+        elements: vec!["true"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -145,9 +166,11 @@ fn arbitrary_variants() {
         source: "[&:nth-child(3)]:underline",
         important: false,
         negative: false,
-        variants: vec!["[&:nth-child(3)]"],
-        elements: vec!["underline"],
+        variants: vec!["[&:nth-child(3)]"].into(),
+        elements: vec!["underline"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -166,17 +189,21 @@ fn test_data_attribute() {
             source: "data-[open]:flex-col",
             important: false,
             negative: false,
-            variants: vec!["data-[open]"],
-            elements: vec!["flex", "col"],
+            variants: vec!["data-[open]"].into(),
+            elements: vec!["flex", "col"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
         Ok(AstStyle {
             source: "data-[close]:flex-row",
             important: false,
             negative: false,
-            variants: vec!["data-[close]"],
-            elements: vec!["flex", "row"],
+            variants: vec!["data-[close]"].into(),
+            elements: vec!["flex", "row"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
     ];
 
@@ -191,9 +218,11 @@ fn test_variants() {
         source: "dark:lg:hover:[&>*]:line-through",
         important: false,
         negative: false,
-        variants: vec!["dark", "lg", "hover", "[&>*]"],
-        elements: vec!["line", "through"],
+        variants: vec!["dark", "lg", "hover", "[&>*]"].into(),
+        elements: vec!["line", "through"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected);
 }
@@ -222,6 +251,12 @@ fn test_take_until_unbalanced() {
     assert_eq!(result, ASTVariant::ArbitraryAttribute("[&:nth-child(3)]"));
 }
 
+#[test]
+fn test_take_until_unbalanced_trailing_escape() {
+    assert_eq!(take_until_unbalanced('(', ')')("--x\\"), Ok(("", "--x\\")));
+    assert!(take_until_unbalanced('(', ')')("(--x\\").is_err());
+}
+
 #[test]
 fn test_nested_variants() {
     let class = "[&[data-open]]:line-through";
@@ -230,9 +265,11 @@ fn test_nested_variants() {
         source: "[&[data-open]]:line-through",
         important: false,
         negative: false,
-        variants: vec!["[&[data-open]]"],
-        elements: vec!["line", "through"],
+        variants: vec!["[&[data-open]]"].into(),
+        elements: vec!["line", "through"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
 
     assert_eq!(result, expected);
@@ -248,9 +285,11 @@ fn non_tailwind() {
             source: "flex",
             important: false,
             negative: false,
-            variants: vec![],
-            elements: vec!["flex"],
+            variants: vec![].into(),
+            elements: vec!["flex"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
     ];
     assert_eq!(result, expected)
@@ -264,9 +303,11 @@ fn test_double_arbitrary() {
         source: "[&>*]:[color:blue]",
         important: false,
         negative: false,
-        variants: vec!["[&>*]"],
-        elements: vec![],
+        variants: vec!["[&>*]"].into(),
+        elements: vec![].into(),
         arbitrary: Some("color:blue"),
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -281,9 +322,11 @@ fn test_named_group_variant() {
         source: "group-hover/dropdown:opacity-100",
         important: false,
         negative: false,
-        variants: vec!["group-hover/dropdown"],
-        elements: vec!["opacity", "100"],
+        variants: vec!["group-hover/dropdown"].into(),
+        elements: vec!["opacity", "100"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -296,9 +339,11 @@ fn test_named_group_variant_visible() {
         source: "group-hover/dropdown:visible",
         important: false,
         negative: false,
-        variants: vec!["group-hover/dropdown"],
-        elements: vec!["visible"],
+        variants: vec!["group-hover/dropdown"].into(),
+        elements: vec!["visible"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -311,9 +356,11 @@ fn test_named_peer_variant() {
         source: "peer-checked/label:text-blue-500",
         important: false,
         negative: false,
-        variants: vec!["peer-checked/label"],
-        elements: vec!["text", "blue", "500"],
+        variants: vec!["peer-checked/label"].into(),
+        elements: vec!["text", "blue", "500"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -326,9 +373,11 @@ fn test_named_group_focus_within() {
         source: "group-focus-within/dropdown:scale-100",
         important: false,
         negative: false,
-        variants: vec!["group-focus-within/dropdown"],
-        elements: vec!["scale", "100"],
+        variants: vec!["group-focus-within/dropdown"].into(),
+        elements: vec!["scale", "100"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -343,25 +392,31 @@ fn test_multiple_named_group_classes() {
             source: "group-hover/dropdown:opacity-100",
             important: false,
             negative: false,
-            variants: vec!["group-hover/dropdown"],
-            elements: vec!["opacity", "100"],
+            variants: vec!["group-hover/dropdown"].into(),
+            elements: vec!["opacity", "100"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
         Ok(AstStyle {
             source: "group-hover/dropdown:visible",
             important: false,
             negative: false,
-            variants: vec!["group-hover/dropdown"],
-            elements: vec!["visible"],
+            variants: vec!["group-hover/dropdown"].into(),
+            elements: vec!["visible"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
         Ok(AstStyle {
             source: "group-hover/dropdown:pointer-events-auto",
             important: false,
             negative: false,
-            variants: vec!["group-hover/dropdown"],
-            elements: vec!["pointer", "events", "auto"],
+            variants: vec!["group-hover/dropdown"].into(),
+            elements: vec!["pointer", "events", "auto"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
     ];
     assert_eq!(result, expected)
@@ -377,9 +432,11 @@ fn test_child_selector_variant() {
         source: "*:text-gray-500",
         important: false,
         negative: false,
-        variants: vec!["*"],
-        elements: vec!["text", "gray", "500"],
+        variants: vec!["*"].into(),
+        elements: vec!["text", "gray", "500"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -392,9 +449,11 @@ fn test_child_selector_with_hover() {
         source: "hover:*:text-blue-500",
         important: false,
         negative: false,
-        variants: vec!["hover", "*"],
-        elements: vec!["text", "blue", "500"],
+        variants: vec!["hover", "*"].into(),
+        elements: vec!["text", "blue", "500"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -407,9 +466,11 @@ fn test_aria_selected_with_child_selector() {
         source: "aria-selected:*:ring-2",
         important: false,
         negative: false,
-        variants: vec!["aria-selected", "*"],
-        elements: vec!["ring", "2"],
+        variants: vec!["aria-selected", "*"].into(),
+        elements: vec!["ring", "2"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -423,25 +484,31 @@ fn test_multiple_child_selector_classes() {
             source: "*:p-4",
             important: false,
             negative: false,
-            variants: vec!["*"],
-            elements: vec!["p", "4"],
+            variants: vec!["*"].into(),
+            elements: vec!["p", "4"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
         Ok(AstStyle {
             source: "*:rounded",
             important: false,
             negative: false,
-            variants: vec!["*"],
-            elements: vec!["rounded"],
+            variants: vec!["*"].into(),
+            elements: vec!["rounded"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
         Ok(AstStyle {
             source: "hover:*:bg-gray-100",
             important: false,
             negative: false,
-            variants: vec!["hover", "*"],
-            elements: vec!["bg", "gray", "100"],
+            variants: vec!["hover", "*"].into(),
+            elements: vec!["bg", "gray", "100"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
     ];
     assert_eq!(result, expected)
@@ -457,9 +524,11 @@ fn test_descendant_selector_variant() {
         source: "**:text-gray-500",
         important: false,
         negative: false,
-        variants: vec!["**"],
-        elements: vec!["text", "gray", "500"],
+        variants: vec!["**"].into(),
+        elements: vec!["text", "gray", "500"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -474,9 +543,11 @@ fn test_container_query_variant_lg() {
         source: "@lg:max-w-2xl",
         important: false,
         negative: false,
-        variants: vec!["@lg"],
-        elements: vec!["max", "w", "2xl"],
+        variants: vec!["@lg"].into(),
+        elements: vec!["max", "w", "2xl"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -489,9 +560,11 @@ fn test_container_query_variant_sm() {
         source: "@sm:grid-cols-3",
         important: false,
         negative: false,
-        variants: vec!["@sm"],
-        elements: vec!["grid", "cols", "3"],
+        variants: vec!["@sm"].into(),
+        elements: vec!["grid", "cols", "3"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -505,9 +578,11 @@ fn test_container_query_variant_max() {
         source: "@max-md:grid-cols-1",
         important: false,
         negative: false,
-        variants: vec!["@max-md"],
-        elements: vec!["grid", "cols", "1"],
+        variants: vec!["@max-md"].into(),
+        elements: vec!["grid", "cols", "1"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -521,9 +596,11 @@ fn test_named_container_query_variant() {
         source: "@lg/sidebar:flex",
         important: false,
         negative: false,
-        variants: vec!["@lg/sidebar"],
-        elements: vec!["flex"],
+        variants: vec!["@lg/sidebar"].into(),
+        elements: vec!["flex"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -536,9 +613,11 @@ fn test_container_query_with_other_variants() {
         source: "hover:@lg:bg-blue-500",
         important: false,
         negative: false,
-        variants: vec!["hover", "@lg"],
-        elements: vec!["bg", "blue", "500"],
+        variants: vec!["hover", "@lg"].into(),
+        elements: vec!["bg", "blue", "500"].into(),
         arbitrary: None,
+        css_variable: None,
+        modifier: None,
     })];
     assert_eq!(result, expected)
 }
@@ -552,26 +631,154 @@ fn test_multiple_container_query_classes() {
             source: "@sm:grid-cols-1",
             important: false,
             negative: false,
-            variants: vec!["@sm"],
-            elements: vec!["grid", "cols", "1"],
+            variants: vec!["@sm"].into(),
+            elements: vec!["grid", "cols", "1"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
         Ok(AstStyle {
             source: "@md:grid-cols-2",
             important: false,
             negative: false,
-            variants: vec!["@md"],
-            elements: vec!["grid", "cols", "2"],
+            variants: vec!["@md"].into(),
+            elements: vec!["grid", "cols", "2"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: None,
         }),
         Ok(AstStyle {
             source: "@lg:grid-cols-4",
             important: false,
             negative: false,
-            variants: vec!["@lg"],
-            elements: vec!["grid", "cols", "4"],
+            variants: vec!["@lg"].into(),
+            elements: vec!["grid", "cols", "4"].into(),
+            arbitrary: None,
+            css_variable: None,
+            modifier: None,
+        }),
+    ];
+    assert_eq!(result, expected)
+}
+
+// v4 CSS variable shorthand
+// https://tailwindcss.com/docs/adding-custom-styles#using-arbitrary-values
+#[test]
+fn test_css_variable_shorthand() {
+    let class = "bg-(--brand) md:text-(length:--size)! -mt-(--gap)";
+    let result = parse_tailwind(class);
+    let expected = vec![
+        Ok(AstStyle {
+            source: "bg-(--brand)",
+            important: false,
+            negative: false,
+            variants: vec![].into(),
+            elements: vec!["bg"].into(),
+            arbitrary: None,
+            css_variable: Some(CssVariable { label: None, name: "--brand" }),
+            modifier: None,
+        }),
+        Ok(AstStyle {
+            source: "md:text-(length:--size)!",
+            important: true,
+            negative: false,
+            variants: vec!["md"].into(),
+            elements: vec!["text"].into(),
+            arbitrary: None,
+            css_variable: Some(CssVariable { label: Some("length"), name: "--size" }),
+            modifier: None,
+        }),
+        Ok(AstStyle {
+            source: "-mt-(--gap)",
+            important: false,
+            negative: true,
+            variants: vec![].into(),
+            elements: vec!["mt"].into(),
+            arbitrary: None,
+            css_variable: Some(CssVariable { label: None, name: "--gap" }),
+            modifier: None,
+        }),
+    ];
+    assert_eq!(result, expected)
+}
+
+#[test]
+fn test_css_variable_requires_variable_name() {
+    let class = "bg-(brand) bg-(--brand";
+    let result = parse_tailwind(class);
+    assert_eq!(result, vec![Err("bg-(brand)"), Err("bg-(--brand")]);
+}
+
+// Postfix modifiers
+// https://tailwindcss.com/docs/background-color#changing-the-opacity
+// https://tailwindcss.com/docs/font-size#setting-the-line-height
+#[test]
+fn test_postfix_modifier() {
+    let class = "bg-red-500/50 text-lg/7 bg-[#fff]/[0.3] text-sm/[17px]";
+    let result = parse_tailwind(class);
+    let expected = vec![
+        Ok(AstStyle {
+            source: "bg-red-500/50",
+            important: false,
+            negative: false,
+            variants: vec![].into(),
+            elements: vec!["bg", "red", "500"].into(),
             arbitrary: None,
+            css_variable: None,
+            modifier: Some("50"),
+        }),
+        Ok(AstStyle {
+            source: "text-lg/7",
+            important: false,
+            negative: false,
+            variants: vec![].into(),
+            elements: vec!["text", "lg"].into(),
+            arbitrary: None,
+            css_variable: None,
+            modifier: Some("7"),
+        }),
+        Ok(AstStyle {
+            source: "bg-[#fff]/[0.3]",
+            important: false,
+            negative: false,
+            variants: vec![].into(),
+            elements: vec!["bg"].into(),
+            arbitrary: Some("#fff"),
+            css_variable: None,
+            modifier: Some("[0.3]"),
+        }),
+        Ok(AstStyle {
+            source: "text-sm/[17px]",
+            important: false,
+            negative: false,
+            variants: vec![].into(),
+            elements: vec!["text", "sm"].into(),
+            arbitrary: None,
+            css_variable: None,
+            modifier: Some("[17px]"),
         }),
     ];
     assert_eq!(result, expected)
 }
+
+#[test]
+fn test_postfix_modifier_with_fraction() {
+    let class = "aspect-16/9!";
+    let result = parse_tailwind(class).into_iter().next().unwrap().unwrap();
+    assert!(result.important);
+    assert_eq!(*result.elements, ["aspect", "16"]);
+    assert_eq!(result.modifier, Some("9"));
+    assert_eq!(result.elements_with_modifier().as_deref(), Some(&["aspect", "16/9"][..]));
+}
+
+#[test]
+fn test_postfix_modifier_trailing_escape() {
+    let result = parse_tailwind("bg-red-500/[0.5\\ text-sm/[17px\\ text-lg/(--lh\\");
+    assert_eq!(result, vec![Err("bg-red-500/[0.5\\"), Err("text-sm/[17px\\"), Err("text-lg/(--lh\\")]);
+}
+
+#[test]
+fn test_split_classes_offsets() {
+    let classes: Vec<_> = split_classes(&["flex  p-2\t", "", " é-1\u{3000}m-2"]).collect();
+    assert_eq!(classes, [(0, "flex"), (6, "p-2"), (13, "é-1"), (20, "m-2")]);
+}
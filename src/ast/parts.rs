@@ -0,0 +1,101 @@
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+// Enough for `group-hover/card:md:dark:grid-cols-subgrid` and nearly every other class.
+const INLINE: usize = 6;
+
+/// The variants or the elements of a class.
+///
+/// Kept inline unless a class has more than a few, so parsing a class doesn't allocate.
+#[derive(Clone)]
+pub(crate) enum Parts<'a> {
+    Inline(usize, [&'a str; INLINE]),
+    Heap(Vec<&'a str>),
+}
+
+impl<'a> Parts<'a> {
+    pub fn push(&mut self, part: &'a str) {
+        match self {
+            Self::Inline(len, parts) if *len < INLINE => {
+                parts[*len] = part;
+                *len += 1;
+            }
+            Self::Inline(_, parts) => {
+                let mut heap = Vec::with_capacity(INLINE * 2);
+                heap.extend_from_slice(parts);
+                heap.push(part);
+                *self = Self::Heap(heap);
+            }
+            Self::Heap(parts) => parts.push(part),
+        }
+    }
+}
+
+impl Default for Parts<'_> {
+    fn default() -> Self {
+        Self::Inline(0, [""; INLINE])
+    }
+}
+
+impl<'a> Deref for Parts<'a> {
+    type Target = [&'a str];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Inline(len, parts) => &parts[..*len],
+            Self::Heap(parts) => parts,
+        }
+    }
+}
+
+impl DerefMut for Parts<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Inline(len, parts) => &mut parts[..*len],
+            Self::Heap(parts) => parts,
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Parts<'a> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut parts = Self::default();
+        iter.into_iter().for_each(|part| parts.push(part));
+        parts
+    }
+}
+
+impl<'a> From<Vec<&'a str>> for Parts<'a> {
+    fn from(parts: Vec<&'a str>) -> Self {
+        parts.into_iter().collect()
+    }
+}
+
+impl fmt::Debug for Parts<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl PartialEq for Parts<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for Parts<'_> {}
+
+/* ========================================================== */
+/*                       🧪 TESTS 🧪                          */
+/* ========================================================== */
+
+#[test]
+fn spills_to_the_heap() {
+    let mut parts: Parts = ["a", "b", "c", "d", "e", "f"].into_iter().collect();
+    assert!(matches!(parts, Parts::Inline(6, _)));
+    parts.push("g");
+    assert!(matches!(parts, Parts::Heap(_)));
+    assert_eq!(*parts, ["a", "b", "c", "d", "e", "f", "g"]);
+    assert_eq!(parts, Parts::from(vec!["a", "b", "c", "d", "e", "f", "g"]));
+    assert_eq!(format!("{:?}", Parts::from(vec!["p", "4"])), r#"["p", "4"]"#);
+}
@@ -0,0 +1,306 @@
+//! Merge, explain and lint Tailwind classes from the command line.
+//!
+//! Reads one class list per line on stdin, or scans Rust sources for the classes they use.
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use tw_merge::merge::{ClassStatus, TailwindConfig, Theme, TwMerger};
+use tw_merge::scan::{rust_files, safelist, scan_paths, sort_source};
+
+const USAGE: &str = "\
+Usage: tw-merge <COMMAND> [OPTIONS] < classes.txt
+       tw-merge scan [PATHS]... [--output <PATH>] [--locations]
+       tw-merge sort [PATHS]... [--check]
+
+Reads one class list per line on stdin.
+
+Commands:
+  merge    Write the merged classes of each line
+  explain  Explain why each class was kept or dropped
+  lint     Report unknown and conflicting classes, exits with 1 if there are any
+  sort     Sort the classes of each line, or of the class strings in the .rs files of the paths
+  scan     Write a safelist of the classes used in the .rs files of the paths [default: .]
+
+Options:
+  --output <PATH>          Write the scan to a file instead of stdout
+  --locations              Write `path:line:column: class` for each class found by scan
+  --check                  List the files sort would change instead of writing them,
+                           exits with 1 if there are any
+  --prefix <PREFIX>        Prefix of the Tailwind classes [default: none]
+  --separator <SEPARATOR>  Separator of the variants [default: :]
+  --config <PATH>          A Tailwind v4 stylesheet with @theme blocks (.css),
+                           or a JSON dump of a Tailwind v3 config (.json)
+  -h, --help               Print this help";
+
+enum Command {
+    Merge,
+    Explain,
+    Lint,
+    Sort { paths: Vec<PathBuf>, check: bool },
+    Scan { paths: Vec<PathBuf>, output: Option<PathBuf>, locations: bool },
+}
+
+fn main() -> ExitCode {
+    let (command, merger) = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match command {
+        Command::Scan { paths, output: Some(path), locations } => {
+            File::create(path).and_then(|file| scan(&paths, locations, &mut BufWriter::new(file)))
+        }
+        Command::Scan { paths, output: None, locations } => scan(&paths, locations, &mut io::stdout().lock()),
+        Command::Sort { paths, check } if !paths.is_empty() => sort(&paths, check, &merger, &mut io::stdout().lock()),
+        command => run(&command, &merger, io::stdin().lock(), &mut io::stdout().lock()),
+    };
+    match result {
+        Ok(0) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+// `None` if help was requested.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<(Command, TwMerger)>, String> {
+    let mut command = None;
+    let (mut prefix, mut separator, mut config) = (None, None, None);
+    let (mut paths, mut output, mut locations, mut check) = (Vec::new(), None, false, false);
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or_else(|| format!("{name} needs a value"));
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--prefix" => prefix = Some(value("--prefix")?),
+            "--separator" => separator = Some(value("--separator")?),
+            "--config" => config = Some(value("--config")?),
+            "--output" => output = Some(PathBuf::from(value("--output")?)),
+            "--locations" => locations = true,
+            "--check" => check = true,
+            "merge" if command.is_none() => command = Some(Command::Merge),
+            "explain" if command.is_none() => command = Some(Command::Explain),
+            "lint" if command.is_none() => command = Some(Command::Lint),
+            "sort" if command.is_none() => command = Some(Command::Sort { paths: Vec::new(), check: false }),
+            "scan" if command.is_none() => {
+                command = Some(Command::Scan { paths: Vec::new(), output: None, locations: false })
+            }
+            _ if matches!(command, Some(Command::Scan { .. } | Command::Sort { .. })) && !arg.starts_with('-') => {
+                paths.push(arg.into())
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    let scan_only = output.is_some() || locations;
+    let command = match command.ok_or("missing command")? {
+        Command::Sort { .. } if check && paths.is_empty() => return Err("`--check` needs files to check".into()),
+        Command::Sort { .. } if !scan_only => Command::Sort { paths, check },
+        Command::Scan { .. } if !check => {
+            if paths.is_empty() {
+                paths.push(PathBuf::from("."));
+            }
+            Command::Scan { paths, output, locations }
+        }
+        _ if check => return Err("`--check` only applies to sort".into()),
+        _ if scan_only => return Err("`--output` and `--locations` only apply to scan".into()),
+        command => command,
+    };
+
+    let default = |theme| TailwindConfig { separator: ":".to_string(), theme, ..Default::default() };
+    let config = match config {
+        Some(path) if path.ends_with(".json") => TailwindConfig::from_json_file(&path),
+        Some(path) => Theme::from_css_file(&path).map(default),
+        None => Ok(default(Theme::default())),
+    };
+    let mut config = config.map_err(|error| format!("could not load the config: {error}"))?;
+    // Flags take precedence over the config.
+    config.prefix = prefix.unwrap_or(config.prefix);
+    config.separator = separator.unwrap_or(config.separator);
+    Ok(Some((command, config.into_merger())))
+}
+
+// Returns the number of lint issues.
+fn run(command: &Command, merger: &TwMerger, input: impl BufRead, output: &mut impl Write) -> io::Result<usize> {
+    let mut issues = 0;
+    for (number, line) in input.lines().enumerate() {
+        let line = line?;
+        let number = number + 1;
+        match command {
+            Command::Merge => writeln!(output, "{}", merger.merge(&[&line]))?,
+            Command::Sort { .. } => writeln!(output, "{}", merger.sort_classes(&[&line]))?,
+            Command::Explain => {
+                if number > 1 {
+                    writeln!(output)?;
+                }
+                writeln!(output, "{}", merger.explain(&[&line]))?;
+            }
+            Command::Lint => {
+                let (_, diagnostics) = merger.merge_lenient(&[&line]);
+                for diagnostic in &diagnostics {
+                    write!(
+                        output,
+                        "{number}:{}: {} `{}`",
+                        diagnostic.span.start + 1,
+                        diagnostic.error,
+                        diagnostic.value.unwrap_or(diagnostic.class)
+                    )?;
+                    match diagnostic.group {
+                        Some(group) => writeln!(output, " ({group})")?,
+                        None => writeln!(output)?,
+                    }
+                }
+                let explanation = merger.explain(&[&line]);
+                for class in explanation.dropped() {
+                    let ClassStatus::Dropped(dropped_by) = class.status else { continue };
+                    let column = class.class.as_ptr() as usize - line.as_ptr() as usize + 1;
+                    let message = match class.collision_id == Some(dropped_by.via) {
+                        true => "redundant",
+                        false => "conflicting",
+                    };
+                    writeln!(
+                        output,
+                        "{number}:{column}: {message} `{}`, overridden by `{}`",
+                        class.class, dropped_by.class
+                    )?;
+                }
+                issues += diagnostics.len() + explanation.dropped().count();
+            }
+            Command::Scan { .. } => unreachable!("scan doesn't read stdin"),
+        }
+    }
+    Ok(issues)
+}
+
+// Returns the number of files to sort with `check`.
+fn sort(paths: &[PathBuf], check: bool, merger: &TwMerger, output: &mut impl Write) -> io::Result<usize> {
+    let mut changed = 0;
+    for path in rust_files(paths)? {
+        let source = fs::read_to_string(&path)?;
+        let sorted = sort_source(&source, merger);
+        if sorted != source {
+            if !check {
+                fs::write(&path, sorted)?;
+            }
+            writeln!(output, "{}", path.display())?;
+            changed += 1;
+        }
+    }
+    Ok(if check { changed } else { 0 })
+}
+
+fn scan(paths: &[PathBuf], locations: bool, output: &mut impl Write) -> io::Result<usize> {
+    let found = scan_paths(paths)?;
+    match locations {
+        true => {
+            for class in &found {
+                writeln!(output, "{}:{}:{}: {}", class.path.display(), class.line, class.column, class.class)?;
+            }
+        }
+        false => write!(output, "{}", safelist(found.iter().map(|class| class.class.as_str())))?,
+    }
+    output.flush()?;
+    Ok(0)
+}
+
+/* ========================================================== */
+/*                       🧪 TESTS 🧪                          */
+/* ========================================================== */
+
+#[cfg(test)]
+fn run_str(args: &[&str], input: &str) -> (String, usize) {
+    let (command, merger) = parse_args(args.iter().map(|arg| arg.to_string())).unwrap().unwrap();
+    let mut output = Vec::new();
+    let issues = run(&command, &merger, input.as_bytes(), &mut output).unwrap();
+    (String::from_utf8(output).unwrap(), issues)
+}
+
+#[test]
+fn merge() {
+    assert_eq!(run_str(&["merge"], "p-2 p-4\nflex block\n"), ("p-4\nblock\n".into(), 0));
+    assert_eq!(
+        run_str(&["merge", "--prefix", "tw-", "--separator", "|"], "hover|tw-p-2 tw-p-4 hover|tw-p-4").0,
+        "tw-p-4 hover|tw-p-4\n"
+    );
+}
+
+#[test]
+fn explain() {
+    let (output, _) = run_str(&["explain"], "px-2 p-4\nflex");
+    assert_eq!(output, "px-2: dropped by p-4 (padding -> padding-x)\np-4: kept (padding)\n\nflex: kept (display)\n");
+}
+
+#[test]
+fn lint() {
+    let (output, issues) = run_str(&["lint"], "flex\np-2 felx px-2 p-4");
+    assert_eq!(
+        output,
+        "2:5: Invalid Tailwind class `felx`\n\
+         2:1: redundant `p-2`, overridden by `p-4`\n\
+         2:10: conflicting `px-2`, overridden by `p-4`\n"
+    );
+    assert_eq!(issues, 3);
+}
+
+#[test]
+fn args() {
+    assert!(parse_args(["--help"].map(String::from).into_iter()).unwrap().is_none());
+    assert_eq!(
+        parse_args(["merge", "--prefix"].map(String::from).into_iter()).err().unwrap(),
+        "--prefix needs a value"
+    );
+    assert_eq!(
+        parse_args(["merge", "lint"].map(String::from).into_iter()).err().unwrap(),
+        "unexpected argument `lint`"
+    );
+    assert!(parse_args(["--config", "missing.css", "lint"].map(String::from).into_iter()).is_err());
+}
+
+#[test]
+fn scan_args() {
+    let args = |args: &[&str]| parse_args(args.iter().map(|arg| arg.to_string())).map(|args| args.unwrap().0);
+    let Ok(Command::Scan { paths, output, locations }) = args(&["scan", "src", "tests", "--locations"]) else {
+        panic!("expected scan")
+    };
+    assert_eq!((paths, output, locations), (vec!["src".into(), "tests".into()], None, true));
+    let Ok(Command::Scan { paths, .. }) = args(&["scan"]) else { panic!("expected scan") };
+    assert_eq!(paths, [PathBuf::from(".")]);
+    assert!(args(&["lint", "--locations"]).is_err());
+    assert!(args(&["merge", "src"]).is_err());
+}
+
+#[test]
+fn scan_locations() {
+    let mut output = Vec::new();
+    scan(&["tests/fixtures/scan/nested".into()], true, &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.lines().next(), Some("tests/fixtures/scan/nested/card.rs:1:31: rounded-lg"));
+}
+
+#[test]
+fn sort_lines() {
+    assert_eq!(run_str(&["sort"], "p-4 flex\nhover:p-2 p-2").0, "flex p-4\np-2 hover:p-2\n");
+    let args = |args: &[&str]| parse_args(args.iter().map(|arg| arg.to_string())).map(|args| args.unwrap().0);
+    assert!(matches!(args(&["sort", "src", "--check"]), Ok(Command::Sort { paths, check: true }) if paths.len() == 1));
+    assert!(args(&["scan", "--check"]).is_err());
+    assert_eq!(args(&["sort", "--check"]).err().unwrap(), "`--check` needs files to check");
+    assert!(args(&["sort", "--locations"]).is_err());
+}
+
+#[test]
+fn sort_check() {
+    let mut output = Vec::new();
+    let changed = sort(&["tests/fixtures/sort".into()], true, &TwMerger::default(), &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "tests/fixtures/sort/card.rs\n");
+    assert_eq!(changed, 1);
+}
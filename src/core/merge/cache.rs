@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash, Hasher, RandomState};
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard};
+
+use super::MergeOptions;
+
+static GLOBAL_CACHE: LazyLock<MergeCache> = LazyLock::new(|| MergeCache::new(MergeCache::DEFAULT_CAPACITY));
+
+/// A bounded LRU cache of merged class strings. Requires the `cache` feature.
+///
+/// Entries are keyed on the input strings and the [`MergeOptions`], so repeated inputs skip parsing entirely.
+///
+/// With the `cache` feature, [`crate::tw_merge!`] and the `tw_merge_*` functions use [`MergeCache::global`],
+/// unless a [`crate::merge::TwMerger`] is in scope.
+///
+/// Caches of [`MergeCache::SHARD_CAPACITY`] entries or more are split into up to 16 shards, each behind its own lock,
+/// so threads merging different inputs rarely wait on each other. The price is that eviction is least recently used
+/// per shard rather than across the whole cache, an entry can be evicted while an older one in another shard stays.
+/// Smaller caches use a single shard and evict exactly.
+///
+/// A hit still returns a fresh `String`, but it is copied after the shard is unlocked.
+///
+/// ```
+/// use tw_merge::merge::*;
+///
+/// let cache = MergeCache::new(128);
+/// assert_eq!(cache.merge(&["p-2", "p-4"]), "p-4");
+/// assert_eq!(cache.merge(&["p-2", "p-4"]), "p-4");
+///
+/// let stats = cache.stats();
+/// assert_eq!((stats.hits, stats.misses, stats.len), (1, 1, 1));
+/// ```
+pub struct MergeCache {
+    shards: Box<[Mutex<Lru>]>,
+    hasher: RandomState,
+}
+
+/// Statistics of a [`MergeCache`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Merges answered from the cache.
+    pub hits: u64,
+    /// Merges that had to be computed.
+    pub misses: u64,
+    /// Entries dropped to stay within the capacity.
+    pub evictions: u64,
+    /// Entries currently in the cache.
+    pub len: usize,
+    /// Maximum number of entries.
+    pub capacity: usize,
+}
+
+impl CacheStats {
+    /// Ratio of hits to lookups, `0.0` if there were none.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl MergeCache {
+    /// Capacity of [`MergeCache::global`].
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Minimum capacity of each shard, caches smaller than twice this have a single shard.
+    pub const SHARD_CAPACITY: usize = 64;
+
+    const MAX_SHARDS: usize = 16;
+
+    /// Create a cache holding at most `capacity` merged strings. A capacity of `0` disables caching.
+    ///
+    /// The number of shards is decided here, [`MergeCache::set_capacity`] keeps it.
+    pub fn new(capacity: usize) -> Self {
+        let count = (capacity / Self::SHARD_CAPACITY).clamp(1, Self::MAX_SHARDS);
+        let shards = (0..count).map(|shard| Mutex::new(Lru::new(shard_capacity(capacity, count, shard)))).collect();
+        Self { shards, hasher: RandomState::new() }
+    }
+
+    /// The cache used by [`crate::tw_merge!`] and the `tw_merge_*` functions.
+    pub fn global() -> &'static Self {
+        &GLOBAL_CACHE
+    }
+
+    /// Merges the Tailwind classes in the provided strings with the global options, see [`crate::merge::tw_merge_slice`].
+    pub fn merge(&self, class: &[&str]) -> String {
+        self.merge_options(class, Default::default())
+    }
+
+    /// Merges the Tailwind classes in the provided strings with the provided options.
+    pub fn merge_options(&self, class: &[&str], options: MergeOptions) -> String {
+        let hash = self.hash(class, options);
+        let cached = self.lock(hash).get(hash, class, options);
+        if let Some(merged) = cached {
+            return String::from(&*merged);
+        }
+        // Merge without holding the lock, other threads may do the same work.
+        let merged = super::merge_uncached(class, options);
+        self.lock(hash).insert(hash, class, options, Arc::from(&*merged));
+        merged
+    }
+
+    /// Hit and miss statistics since the cache was created or last cleared.
+    pub fn stats(&self) -> CacheStats {
+        self.shards.iter().map(lock).fold(CacheStats::default(), |total, lru| CacheStats {
+            hits: total.hits + lru.stats.hits,
+            misses: total.misses + lru.stats.misses,
+            evictions: total.evictions + lru.stats.evictions,
+            len: total.len + lru.map.len(),
+            capacity: total.capacity + lru.capacity,
+        })
+    }
+
+    /// Remove every entry and reset the statistics.
+    pub fn clear(&self) {
+        for mut lru in self.shards.iter().map(lock) {
+            *lru = Lru::new(lru.capacity);
+        }
+    }
+
+    /// Change the maximum number of entries, evicting the least recently used ones if needed.
+    pub fn set_capacity(&self, capacity: usize) {
+        let count = self.shards.len();
+        for (shard, mut lru) in self.shards.iter().map(lock).enumerate() {
+            lru.capacity = shard_capacity(capacity, count, shard);
+            while lru.map.len() > lru.capacity {
+                let index = lru.evict();
+                lru.free.push(index);
+            }
+        }
+    }
+
+    fn hash(&self, class: &[&str], options: MergeOptions) -> u64 {
+        let mut hasher = self.hasher.build_hasher();
+        class.hash(&mut hasher);
+        options.prefix.hash(&mut hasher);
+        options.separator.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    // The high bits pick the shard, the map of the shard hashes the whole value.
+    fn lock(&self, hash: u64) -> MutexGuard<'_, Lru> {
+        lock(&self.shards[(hash >> 32) as usize % self.shards.len()])
+    }
+}
+
+fn lock(shard: &Mutex<Lru>) -> MutexGuard<'_, Lru> {
+    // A panic while holding the lock can't leave the cache inconsistent.
+    shard.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// The first `capacity % count` shards hold one more entry.
+fn shard_capacity(capacity: usize, count: usize, shard: usize) -> usize {
+    capacity / count + usize::from(shard < capacity % count)
+}
+
+impl std::fmt::Debug for MergeCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergeCache").field("stats", &self.stats()).finish()
+    }
+}
+
+// Least recently used list over a slab of entries.
+struct Lru {
+    capacity: usize,
+    map: HashMap<u64, usize>,
+    entries: Vec<Entry>,
+    // Slots left behind by `set_capacity`
+    free: Vec<usize>,
+    // Most recently used
+    head: Option<usize>,
+    // Least recently used
+    tail: Option<usize>,
+    stats: CacheStats,
+}
+
+struct Entry {
+    hash: u64,
+    class: Box<[Box<str>]>,
+    prefix: &'static str,
+    separator: &'static str,
+    merged: Arc<str>,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+impl Entry {
+    fn is(&self, class: &[&str], options: MergeOptions) -> bool {
+        self.prefix == options.prefix
+            && self.separator == options.separator
+            && self.class.len() == class.len()
+            && self.class.iter().zip(class).all(|(cached, class)| &**cached == *class)
+    }
+}
+
+impl Lru {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            map: HashMap::new(),
+            entries: Vec::new(),
+            free: Vec::new(),
+            head: None,
+            tail: None,
+            stats: Default::default(),
+        }
+    }
+
+    fn get(&mut self, hash: u64, class: &[&str], options: MergeOptions) -> Option<Arc<str>> {
+        match self.map.get(&hash) {
+            Some(&index) if self.entries[index].is(class, options) => {
+                self.stats.hits += 1;
+                self.unlink(index);
+                self.push_front(index);
+                Some(self.entries[index].merged.clone())
+            }
+            _ => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn insert(&mut self, hash: u64, class: &[&str], options: MergeOptions, merged: Arc<str>) {
+        if self.capacity == 0 {
+            return;
+        }
+        // Another thread inserted it, or a different input with the same hash.
+        if let Some(&index) = self.map.get(&hash) {
+            self.unlink(index);
+            self.push_front(index);
+            let entry = &mut self.entries[index];
+            if !entry.is(class, options) {
+                entry.class = class.iter().map(|class| Box::from(*class)).collect();
+                (entry.prefix, entry.separator) = (options.prefix, options.separator);
+                entry.merged = merged;
+            }
+            return;
+        }
+
+        let entry = Entry {
+            hash,
+            class: class.iter().map(|class| Box::from(*class)).collect(),
+            prefix: options.prefix,
+            separator: options.separator,
+            merged,
+            prev: None,
+            next: None,
+        };
+        let slot = match self.map.len() < self.capacity {
+            true => self.free.pop(),
+            false => Some(self.evict()),
+        };
+        let index = match slot {
+            Some(index) => {
+                self.entries[index] = entry;
+                index
+            }
+            None => {
+                self.entries.push(entry);
+                self.entries.len() - 1
+            }
+        };
+        self.map.insert(hash, index);
+        self.push_front(index);
+    }
+
+    // Drops the least recently used entry, returns its slot.
+    fn evict(&mut self) -> usize {
+        let index = self.tail.expect("evicting from an empty cache");
+        self.unlink(index);
+        self.map.remove(&self.entries[index].hash);
+        self.stats.evictions += 1;
+        index
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let Entry { prev, next, .. } = self.entries[index];
+        match prev {
+            Some(prev) => self.entries[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.entries[next].prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn push_front(&mut self, index: usize) {
+        self.entries[index].prev = None;
+        self.entries[index].next = self.head;
+        match self.head {
+            Some(head) => self.entries[head].prev = Some(index),
+            None => self.tail = Some(index),
+        }
+        self.head = Some(index);
+    }
+}
+
+/* ========================================================== */
+/*                       🧪 TESTS 🧪                          */
+/* ========================================================== */
+
+#[test]
+fn evicts_least_recently_used() {
+    let cache = MergeCache::new(2);
+    cache.merge(&["p-1"]);
+    cache.merge(&["p-2"]);
+    // p-1 is now the most recently used
+    cache.merge(&["p-1"]);
+    cache.merge(&["p-3"]);
+
+    let lru = lock(&cache.shards[0]);
+    let cached: Vec<&str> = lru.map.values().map(|index| &*lru.entries[*index].class[0]).collect();
+    assert_eq!(cached.len(), 2);
+    assert!(cached.contains(&"p-1") && cached.contains(&"p-3"));
+    assert_eq!(lru.stats.evictions, 1);
+}
+
+#[test]
+fn shrink_and_grow() {
+    let cache = MergeCache::new(3);
+    for class in ["p-1", "p-2", "p-3"] {
+        cache.merge(&[class]);
+    }
+    cache.set_capacity(1);
+    assert_eq!(cache.stats().len, 1);
+
+    cache.set_capacity(3);
+    for class in ["p-4", "p-5", "p-3"] {
+        cache.merge(&[class]);
+    }
+    let stats = cache.stats();
+    assert_eq!((stats.len, stats.hits), (3, 1));
+}
+
+#[test]
+fn shards_large_caches() {
+    assert_eq!(MergeCache::new(100).shards.len(), 1);
+    assert_eq!(MergeCache::new(MergeCache::DEFAULT_CAPACITY).shards.len(), 16);
+
+    let cache = MergeCache::new(200);
+    assert_eq!(cache.shards.len(), 3);
+    for padding in 0..600 {
+        cache.merge(&[&format!("p-{padding}")]);
+    }
+    let stats = cache.stats();
+    assert_eq!((stats.len, stats.capacity, stats.evictions), (200, 200, 400));
+    assert!(cache.shards.iter().map(lock).all(|lru| lru.map.len() == lru.capacity));
+
+    cache.set_capacity(10);
+    assert_eq!(cache.stats().len, 10);
+}
@@ -0,0 +1,616 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::LazyLock;
+
+use super::{CollisionIdFn, GetCollisionsFn};
+use crate::ast::{AstStyle, Parts};
+
+static DEFAULT_TABLE: LazyLock<CollisionTable> = LazyLock::new(CollisionTable::default);
+
+/// The class could not be parsed, e.g. `bg-[red` or `hover:`.
+pub const INVALID_SYNTAX: &str = "Invalid Tailwind class syntax";
+/// No class group starts with the first element of the class, e.g. `felx`.
+pub const UNKNOWN_UTILITY: &str = "Invalid Tailwind class";
+/// A class group starts with the first element of the class, but the value doesn't match, e.g. `z-top`,
+/// or the value of a color utility is not a color, e.g. `bg-rde-500`.
+pub const INVALID_VALUE: &str = "Invalid Tailwind utility value";
+/// The class is known, but its group doesn't accept negative values, e.g. `-bg-red-500`.
+pub const UNSUPPORTED_NEGATIVE: &str = "Negative value not supported by Tailwind utility";
+/// The class is known, but its group doesn't accept a postfix modifier, e.g. `p-4/50`.
+pub const UNSUPPORTED_MODIFIER: &str = "Modifier not supported by Tailwind utility";
+
+/// Why a class has no CollisionId: one of the errors above,
+/// and the CollisionId of the group closest to the class, if any.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Rejected {
+    pub error: &'static str,
+    pub group: Option<&'static str>,
+}
+
+/// The class groups and collisions used to merge Tailwind classes.
+///
+/// Each class group has a CollisionId and a list of [`ClassPattern`]s.
+/// Patterns are checked in order, the first one matching a class decides its group.
+///
+/// Collisions are the edges between groups, e.g. `padding` knocks out `padding-x`.
+///
+/// ```
+/// use tw_merge::merge::*;
+///
+/// let mut table = CollisionTable::default();
+/// assert_eq!(table.lookup(&["text", "lg"], None), Some("font-size"));
+/// assert!(table.collisions("padding").contains(&"padding-x"));
+///
+/// // btn-primary, btn-[..]
+/// table.add_group("btn", [ClassPattern::new(&[Element::Is("btn")]).with_rest(Rest::Any)]);
+/// assert_eq!(table.lookup(&["btn", "primary"], None), Some("btn"));
+///
+/// let merger = TwMerger::default().with_collision_table(table);
+/// assert_eq!(merger.merge(&["btn-primary btn-secondary"]), "btn-secondary");
+/// ```
+#[derive(Clone)]
+pub struct CollisionTable {
+    rules: Vec<(&'static str, ClassPattern)>,
+    // The default collisions borrow the static edges.
+    collisions: Vec<(&'static str, Cow<'static, [&'static str]>)>,
+    // Rule indexes by literal first element, rules that don't start with a literal are in every list.
+    index: HashMap<&'static str, Vec<usize>>,
+    wildcards: Vec<usize>,
+    // Compact keys of every CollisionId of the rules and collisions, and the collisions of each key.
+    keys: HashMap<&'static str, u32>,
+    edge_keys: Vec<Vec<u32>>,
+    negatives: Vec<&'static str>,
+    // Classes accepting negative values in groups that don't, `-bg-linear-45`.
+    negative_patterns: &'static [(&'static str, ClassPattern)],
+    modifiers: Vec<&'static str>,
+    fractions: Vec<&'static str>,
+    properties: Vec<(&'static str, &'static str)>,
+}
+
+/// A class group of a [`CollisionTable`].
+#[derive(Clone, Debug)]
+pub struct ClassGroup<'a> {
+    /// The CollisionId of the group.
+    pub id: &'static str,
+    /// The patterns of the group, in the order they are checked.
+    pub patterns: Vec<&'a ClassPattern>,
+}
+
+/// Matches the elements and arbitrary value of a class.
+///
+/// `bg-red-500` has the elements `["bg", "red", "500"]`, `m-[2px]` has `["m"]` and the arbitrary value `2px`.
+#[derive(Clone, Copy, Debug)]
+pub struct ClassPattern {
+    /// The leading elements.
+    pub elements: &'static [Element],
+    /// The elements after the leading elements.
+    pub rest: Rest,
+    /// The arbitrary value, an empty string if there is none.
+    pub arbitrary: Arbitrary,
+}
+
+/// Matches a single element of a class.
+#[derive(Clone, Copy)]
+pub enum Element {
+    /// Exactly this element.
+    Is(&'static str),
+    /// Any of these elements.
+    OneOf(&'static [&'static str]),
+    /// Any element.
+    Any,
+    /// An element accepted by the validator.
+    Value(fn(&str) -> bool),
+}
+
+/// Matches the elements after [`ClassPattern::elements`].
+#[derive(Clone, Copy)]
+pub enum Rest {
+    /// No more elements.
+    Empty,
+    /// Any elements, including none.
+    Any,
+    /// Elements accepted by the validator.
+    Value(fn(&[&str]) -> bool),
+}
+
+/// Matches the arbitrary value of a class.
+#[derive(Clone, Copy)]
+pub enum Arbitrary {
+    /// Any arbitrary value, including none.
+    Any,
+    /// No arbitrary value.
+    Empty,
+    /// An arbitrary value accepted by the validator, which receives an empty string if there is none.
+    Value(fn(&str) -> bool),
+}
+
+impl ClassPattern {
+    /// Matches exactly these elements, with any arbitrary value.
+    pub const fn new(elements: &'static [Element]) -> Self {
+        Self { elements, rest: Rest::Empty, arbitrary: Arbitrary::Any }
+    }
+
+    /// Set what may follow the elements.
+    pub const fn with_rest(mut self, rest: Rest) -> Self {
+        self.rest = rest;
+        self
+    }
+
+    /// Set what the arbitrary value must be.
+    pub const fn with_arbitrary(mut self, arbitrary: Arbitrary) -> Self {
+        self.arbitrary = arbitrary;
+        self
+    }
+
+    /// Returns `true` if the class matches this pattern.
+    pub fn matches(&self, elements: &[&str], arbitrary: &str) -> bool {
+        if elements.len() < self.elements.len() {
+            return false;
+        }
+        let (head, rest) = elements.split_at(self.elements.len());
+        self.elements.iter().zip(head).all(|(pattern, element)| pattern.matches(element))
+            && self.rest.matches(rest)
+            && self.arbitrary.matches(arbitrary)
+    }
+}
+
+impl Element {
+    /// Returns `true` if the element matches.
+    pub fn matches(&self, element: &str) -> bool {
+        match self {
+            Self::Is(expected) => *expected == element,
+            Self::OneOf(expected) => expected.contains(&element),
+            Self::Any => true,
+            Self::Value(validator) => validator(element),
+        }
+    }
+}
+
+impl Rest {
+    /// Returns `true` if the remaining elements match.
+    pub fn matches(&self, rest: &[&str]) -> bool {
+        match self {
+            Self::Empty => rest.is_empty(),
+            Self::Any => true,
+            Self::Value(validator) => validator(rest),
+        }
+    }
+}
+
+impl Arbitrary {
+    /// Returns `true` if the arbitrary value matches.
+    pub fn matches(&self, arbitrary: &str) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Empty => arbitrary.is_empty(),
+            Self::Value(validator) => validator(arbitrary),
+        }
+    }
+}
+
+impl fmt::Debug for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Is(element) => write!(f, "Is({element:?})"),
+            Self::OneOf(elements) => write!(f, "OneOf({elements:?})"),
+            Self::Any => write!(f, "Any"),
+            Self::Value(_) => write!(f, "Value(..)"),
+        }
+    }
+}
+
+impl fmt::Debug for Rest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Empty"),
+            Self::Any => write!(f, "Any"),
+            Self::Value(_) => write!(f, "Value(..)"),
+        }
+    }
+}
+
+impl fmt::Debug for Arbitrary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any => write!(f, "Any"),
+            Self::Empty => write!(f, "Empty"),
+            Self::Value(_) => write!(f, "Value(..)"),
+        }
+    }
+}
+
+impl Default for CollisionTable {
+    /// The default Tailwind class groups and collisions.
+    fn default() -> Self {
+        let collisions = super::get_collisions::COLLISIONS.iter().map(|(id, ids)| (*id, Cow::Borrowed(*ids))).collect();
+        let negatives = super::get_collision_id::NEGATIVE_GROUPS.to_vec();
+        let modifiers = super::get_collision_id::MODIFIER_GROUPS.to_vec();
+        let fractions = super::get_collision_id::FRACTION_GROUPS.to_vec();
+        let properties = super::get_collision_id::PROPERTIES.to_vec();
+        Self::from_parts(
+            super::get_collision_id::RULES.to_vec(),
+            collisions,
+            negatives,
+            super::get_collision_id::NEGATIVE_PATTERNS,
+            modifiers,
+            fractions,
+            properties,
+        )
+    }
+}
+
+impl CollisionTable {
+    /// A table without any class group.
+    pub fn empty() -> Self {
+        Self::from_parts(Vec::new(), Vec::new(), Vec::new(), &[], Vec::new(), Vec::new(), Vec::new())
+    }
+
+    /// The default table, shared by every merger that doesn't set its own.
+    pub(crate) fn default_ref() -> &'static Self {
+        &DEFAULT_TABLE
+    }
+
+    fn from_parts(
+        rules: Vec<(&'static str, ClassPattern)>,
+        collisions: Vec<(&'static str, Cow<'static, [&'static str]>)>,
+        negatives: Vec<&'static str>,
+        negative_patterns: &'static [(&'static str, ClassPattern)],
+        modifiers: Vec<&'static str>,
+        fractions: Vec<&'static str>,
+        properties: Vec<(&'static str, &'static str)>,
+    ) -> Self {
+        let mut table = Self {
+            rules,
+            collisions,
+            index: HashMap::new(),
+            wildcards: Vec::new(),
+            keys: HashMap::new(),
+            edge_keys: Vec::new(),
+            negatives,
+            negative_patterns,
+            modifiers,
+            fractions,
+            properties,
+        };
+        table.reindex();
+        table
+    }
+
+    fn rekey(&mut self) {
+        self.keys.clear();
+        let ids = self
+            .rules
+            .iter()
+            .map(|(id, _)| id)
+            .chain(self.collisions.iter().flat_map(|(from, to)| std::iter::once(from).chain(to.iter())));
+        for id in ids {
+            let key = self.keys.len() as u32;
+            self.keys.entry(id).or_insert(key);
+        }
+        self.edge_keys = vec![Vec::new(); self.keys.len()];
+        for (from, to) in &self.collisions {
+            self.edge_keys[self.keys[from] as usize] = to.iter().map(|to| self.keys[to]).collect();
+        }
+    }
+
+    fn reindex(&mut self) {
+        self.index.clear();
+        self.wildcards.clear();
+        for (position, (_, pattern)) in self.rules.iter().enumerate() {
+            match pattern.elements.first() {
+                Some(Element::Is(element)) => self.index.entry(element).or_default().push(position),
+                Some(Element::OneOf(elements)) => {
+                    elements.iter().for_each(|element| self.index.entry(element).or_default().push(position))
+                }
+                _ => self.wildcards.push(position),
+            }
+        }
+        // Wildcards are checked for every element, in table order.
+        for positions in self.index.values_mut() {
+            positions.extend(&self.wildcards);
+            positions.sort_unstable();
+            positions.dedup();
+        }
+        self.rekey();
+    }
+
+    /// The CollisionId of a class, `None` if no pattern matches.
+    ///
+    /// elements: parts of the Tailwind class separated by `-`.
+    ///
+    /// arbitrary: the arbitrary value at the end of the Tailwind class
+    pub fn lookup(&self, elements: &[&str], arbitrary: Option<&str>) -> Option<&'static str> {
+        let arbitrary = arbitrary.unwrap_or_default();
+        let positions = elements.first().and_then(|first| self.index.get(first)).unwrap_or(&self.wildcards);
+        positions
+            .iter()
+            .map(|position| &self.rules[*position])
+            .find_map(|(id, pattern)| pattern.matches(elements, arbitrary).then_some(*id))
+    }
+
+    /// The compact key of a CollisionId, `None` if it is not in the table.
+    /// Keys go from 0 to [`CollisionTable::key_count`].
+    pub(crate) fn key(&self, id: &str) -> Option<u32> {
+        self.keys.get(id).copied()
+    }
+
+    pub(crate) fn key_count(&self) -> u32 {
+        self.keys.len() as u32
+    }
+
+    /// The keys knocked out by the key, same as [`CollisionTable::collisions`].
+    pub(crate) fn collision_keys(&self, key: u32) -> &[u32] {
+        self.edge_keys.get(key as usize).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Index of the first rule of the group.
+    pub(crate) fn rule_position(&self, id: &str) -> Option<usize> {
+        self.rules.iter().position(|(rule_id, _)| *rule_id == id)
+    }
+
+    /// The first class group starting with the same literal element as the class, ignoring wildcard patterns.
+    pub(crate) fn closest_group(&self, elements: &[&str]) -> Option<&'static str> {
+        let positions = self.index.get(elements.first()?)?;
+        positions.iter().find(|position| !self.wildcards.contains(position)).map(|position| self.rules[*position].0)
+    }
+
+    /// The CollisionId of a single class using the global [`crate::merge::MergeOptions`],
+    /// `None` if it is invalid or no pattern matches.
+    ///
+    /// Unlike [`CollisionTable::lookup`], this handles v4 CSS variables (`bg-(--brand)`),
+    /// and arbitrary properties with a [known property](CollisionTable::property) (`[color:red]`).
+    /// Negative classes are `None` unless their group [allows negatives](CollisionTable::allows_negative).
+    pub fn lookup_class(&self, class: &str) -> Option<&'static str> {
+        let class = crate::ast::parse_single(class, &Default::default())?;
+        let css_variable = class.css_variable.map(|variable| variable.to_arbitrary());
+        let arbitrary = class.arbitrary.or(css_variable.as_deref());
+        let Ok(id) = self.class_id(&class, arbitrary, &|_: &[&str], _: Option<&str>| None) else {
+            let (property, _) = arbitrary.filter(|_| class.elements.is_empty())?.split_once(':')?;
+            return self.property(property);
+        };
+        Some(id)
+    }
+
+    /// The CollisionId of a parsed class, from the custom CollisionIdFn or the table.
+    ///
+    /// The class is rejected if its group doesn't accept its `-` prefix or its modifier.
+    pub(crate) fn class_id(
+        &self,
+        class: &AstStyle,
+        arbitrary: Option<&str>,
+        collision_id_fn: &impl CollisionIdFn,
+    ) -> Result<&'static str, Rejected> {
+        self.resolve_class(class, arbitrary, collision_id_fn).map(|(id, _)| id)
+    }
+
+    /// Same as [`CollisionTable::class_id`], along with the elements of the class
+    /// if its modifier is a fraction that is part of the value, `["w", "1/2"]` for `w-1/2`.
+    pub(crate) fn resolve_class<'a>(
+        &self,
+        class: &AstStyle<'a>,
+        arbitrary: Option<&str>,
+        collision_id_fn: &impl CollisionIdFn,
+    ) -> Result<(&'static str, Option<Parts<'a>>), Rejected> {
+        let group =
+            |elements: &[&str]| collision_id_fn.apply(elements, arbitrary).or_else(|| self.lookup(elements, arbitrary));
+        let reject = |error, group| Rejected { error, group };
+        let (id, fraction) = match group(&class.elements) {
+            Some(id) if class.modifier.is_none() || self.accepts_modifier(id) => (id, None),
+            found => {
+                let fraction = class.elements_with_modifier().and_then(|elements| {
+                    let id = group(&elements).filter(|id| self.accepts_fraction(id))?;
+                    Some((id, Some(elements)))
+                });
+                match (fraction, found) {
+                    (Some(fraction), _) => fraction,
+                    (None, Some(id)) if class.negative && !self.allows_negative_class(id, &class.elements) => {
+                        return Err(reject(UNSUPPORTED_NEGATIVE, Some(id)));
+                    }
+                    (None, Some(id)) => return Err(reject(UNSUPPORTED_MODIFIER, Some(id))),
+                    (None, None) => {
+                        return Err(match self.closest_group(&class.elements) {
+                            None => reject(UNKNOWN_UTILITY, None),
+                            group => reject(INVALID_VALUE, group),
+                        });
+                    }
+                }
+            }
+        };
+        // -bg-red-500 is not a class, whichever way its group was found
+        if class.negative && !self.allows_negative_class(id, fraction.as_deref().unwrap_or(&class.elements)) {
+            return Err(reject(UNSUPPORTED_NEGATIVE, Some(id)));
+        }
+        Ok((id, fraction))
+    }
+
+    /// The CollisionId of the class group setting the CSS property, `None` if there is none.
+    ///
+    /// Arbitrary properties with a known property collide like the class group, `[color:red]` is a `text-color`.
+    /// Other arbitrary properties only collide with the same property.
+    pub fn property(&self, property: &str) -> Option<&'static str> {
+        self.properties.iter().find(|(name, _)| *name == property).map(|(_, id)| *id)
+    }
+
+    /// Every `(property, id)` pair, where the arbitrary property collides like the class group `id`.
+    pub fn properties(&self) -> impl Iterator<Item = (&'static str, &'static str)> + '_ {
+        self.properties.iter().copied()
+    }
+
+    /// Make arbitrary properties setting the CSS property collide like the class group,
+    /// replacing its current group if any.
+    pub fn add_property(&mut self, property: &'static str, id: &'static str) {
+        self.remove_property(property);
+        self.properties.push((property, id));
+    }
+
+    /// Remove the CSS property, returns `false` if it was not known.
+    pub fn remove_property(&mut self, property: &str) -> bool {
+        let len = self.properties.len();
+        self.properties.retain(|(name, _)| *name != property);
+        len != self.properties.len()
+    }
+
+    /// Returns `true` if the classes of the group accept a negative value, like `-mt-2`.
+    ///
+    /// Negative classes of other groups are invalid, `-bg-red-500` is not a background color.
+    pub fn allows_negative(&self, id: &str) -> bool {
+        self.negatives.contains(&id)
+    }
+
+    // -bg-linear-45 is a gradient angle, while -bg-none is not a class.
+    fn allows_negative_class(&self, id: &str, elements: &[&str]) -> bool {
+        self.allows_negative(id)
+            || self
+                .negative_patterns
+                .iter()
+                .any(|(pattern_id, pattern)| *pattern_id == id && pattern.matches(elements, ""))
+    }
+
+    /// Allow or reject negative values for the classes of a group.
+    ///
+    /// This also applies to the groups of a theme or a custom [`crate::merge::CollisionIdFn`].
+    pub fn set_negative(&mut self, id: &'static str, allowed: bool) {
+        self.negatives.retain(|negative| *negative != id);
+        if allowed {
+            self.negatives.push(id);
+        }
+    }
+
+    /// Returns `true` if the classes of the group accept a postfix modifier, like `bg-red-500/50` or `text-lg/7`.
+    ///
+    /// Other groups only accept a fraction after the `/` if they [accept fractions](CollisionTable::accepts_fraction),
+    /// `p-4/50` is not a padding.
+    pub fn accepts_modifier(&self, id: &str) -> bool {
+        self.modifiers.contains(&id)
+    }
+
+    /// Accept or reject postfix modifiers for the classes of a group.
+    ///
+    /// This also applies to the groups of a theme or a custom [`crate::merge::CollisionIdFn`].
+    pub fn set_modifier(&mut self, id: &'static str, accepted: bool) {
+        self.modifiers.retain(|modifier| *modifier != id);
+        if accepted {
+            self.modifiers.push(id);
+        }
+    }
+
+    /// Returns `true` if the classes of the group accept a fraction as their value, like `w-1/2`.
+    pub fn accepts_fraction(&self, id: &str) -> bool {
+        self.fractions.contains(&id)
+    }
+
+    /// Accept or reject fractions for the classes of a group.
+    ///
+    /// This also applies to the groups of a theme or a custom [`crate::merge::CollisionIdFn`].
+    pub fn set_fraction(&mut self, id: &'static str, accepted: bool) {
+        self.fractions.retain(|fraction| *fraction != id);
+        if accepted {
+            self.fractions.push(id);
+        }
+    }
+
+    /// The class groups, in the order they are first checked.
+    pub fn groups(&self) -> Vec<ClassGroup<'_>> {
+        let mut groups: Vec<ClassGroup> = Vec::new();
+        for (id, pattern) in &self.rules {
+            match groups.iter_mut().find(|group| group.id == *id) {
+                Some(group) => group.patterns.push(pattern),
+                None => groups.push(ClassGroup { id, patterns: vec![pattern] }),
+            }
+        }
+        groups
+    }
+
+    /// The class group with the given CollisionId.
+    pub fn group(&self, id: &str) -> Option<ClassGroup<'_>> {
+        let patterns: Vec<&ClassPattern> =
+            self.rules.iter().filter(|(group, _)| *group == id).map(|(_, pattern)| pattern).collect();
+        let (id, _) = self.rules.iter().find(|(group, _)| *group == id)?;
+        Some(ClassGroup { id, patterns })
+    }
+
+    /// Add patterns to a class group, they are checked before any existing pattern.
+    pub fn add_group(&mut self, id: &'static str, patterns: impl IntoIterator<Item = ClassPattern>) {
+        let rules: Vec<_> = patterns.into_iter().map(|pattern| (id, pattern)).collect();
+        self.rules.splice(0..0, rules);
+        self.reindex();
+    }
+
+    /// Replace the patterns of a class group.
+    ///
+    /// The new patterns are checked where the first existing pattern was,
+    /// so the group keeps its priority. If the group doesn't exist, this is [`CollisionTable::add_group`].
+    pub fn replace_group(&mut self, id: &'static str, patterns: impl IntoIterator<Item = ClassPattern>) {
+        let position = self.rules.iter().position(|(group, _)| *group == id).unwrap_or_default();
+        self.rules.retain(|(group, _)| *group != id);
+        let rules: Vec<_> = patterns.into_iter().map(|pattern| (id, pattern)).collect();
+        self.rules.splice(position..position, rules);
+        self.reindex();
+    }
+
+    /// Remove a class group, returns `false` if it doesn't exist.
+    ///
+    /// The collisions of the group are kept.
+    pub fn remove_group(&mut self, id: &str) -> bool {
+        let len = self.rules.len();
+        self.rules.retain(|(group, _)| *group != id);
+        self.reindex();
+        len != self.rules.len()
+    }
+
+    /// The CollisionIds knocked out by the given CollisionId, not including itself.
+    pub fn collisions(&self, id: &str) -> &[&'static str] {
+        self.collisions.iter().find(|(from, _)| *from == id).map(|(_, to)| to.as_ref()).unwrap_or_default()
+    }
+
+    /// Every `(from, to)` collision, where a class of group `from` knocks out an earlier class of group `to`.
+    pub fn edges(&self) -> impl Iterator<Item = (&'static str, &'static str)> + '_ {
+        self.collisions.iter().flat_map(|(from, to)| to.iter().map(|to| (*from, *to)))
+    }
+
+    /// Make `from` knock out earlier classes of the given groups.
+    pub fn add_collisions(&mut self, from: &'static str, to: impl IntoIterator<Item = &'static str>) {
+        let index = match self.collisions.iter().position(|(id, _)| *id == from) {
+            Some(index) => index,
+            None => {
+                self.collisions.push((from, Cow::Owned(Vec::new())));
+                self.collisions.len() - 1
+            }
+        };
+        let collisions = self.collisions[index].1.to_mut();
+        for to in to {
+            if !collisions.contains(&to) {
+                collisions.push(to);
+            }
+        }
+        self.rekey();
+    }
+
+    /// Remove every collision of `from`.
+    pub fn remove_collisions(&mut self, from: &str) {
+        self.collisions.retain(|(id, _)| *id != from);
+        self.rekey();
+    }
+}
+
+impl fmt::Debug for CollisionTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CollisionTable")
+            .field("groups", &self.groups().len())
+            .field("collisions", &self.collisions.len())
+            .finish()
+    }
+}
+
+impl CollisionIdFn for CollisionTable {
+    fn apply(&self, elements: &[&str], arbitrary: Option<&str>) -> Option<&'static str> {
+        self.lookup(elements, arbitrary)
+    }
+}
+
+impl GetCollisionsFn for CollisionTable {
+    fn apply(&self, collision_id: &str) -> Option<&[&'static str]> {
+        Some(self.collisions(collision_id))
+    }
+}
@@ -2,7 +2,7 @@ use std::sync::OnceLock;
 
 /// Configuration for merging Tailwind classes.
 /// If you want to set global options use [`set_merge_options`].
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct MergeOptions {
     /// Custom prefix for modifiers in Tailwind classes
     ///
@@ -36,6 +36,8 @@ pub(crate) static MERGE_OVERRIDE: OnceLock<MergeOptions> = OnceLock::new();
 
 /// Set global options for merging Tailwind classes.
 /// Useful for getting all the macros to work with custom options.
+///
+/// This can only be set once, use [`crate::merge::TwMerger`] if you need more than one configuration.
 pub fn set_merge_options(options: MergeOptions) {
     let _ = MERGE_OVERRIDE.set(options);
 }
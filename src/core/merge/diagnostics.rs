@@ -0,0 +1,206 @@
+use std::fmt;
+use std::ops::Range;
+
+use super::collision_table::{INVALID_VALUE, Rejected, UNKNOWN_UTILITY, UNSUPPORTED_MODIFIER};
+use super::get_collision_id::{COLOR_GROUPS, is_color_value};
+use super::tw_merge_override::{Resolved, resolve};
+use super::{CollisionIdFn, CollisionTable, GetCollisionsFn, MergeOptions, TwMerger};
+use crate::ast::AstStyle;
+
+/// A class the merger doesn't know, passed through untouched. See [`merge_strict`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClassDiagnostic<'a> {
+    /// The class as it appears in the input.
+    pub class: &'a str,
+    /// Byte range of the class in the input strings joined by a space,
+    /// which is the range in the input itself if there is only one.
+    pub span: Range<usize>,
+    /// The reason the class was rejected while looking up its group: [`INVALID_SYNTAX`], [`UNKNOWN_UTILITY`],
+    /// [`INVALID_VALUE`], [`UNSUPPORTED_NEGATIVE`] or [`UNSUPPORTED_MODIFIER`].
+    ///
+    /// [`INVALID_SYNTAX`]: super::INVALID_SYNTAX
+    /// [`UNSUPPORTED_NEGATIVE`]: super::UNSUPPORTED_NEGATIVE
+    pub error: &'static str,
+    /// The part of the class that was rejected: the utility for [`UNKNOWN_UTILITY`] (`felx`),
+    /// the value for [`INVALID_VALUE`] (`top` in `z-top`), the modifier for [`UNSUPPORTED_MODIFIER`].
+    pub value: Option<&'a str>,
+    /// For [`INVALID_VALUE`], the CollisionId of the first class group starting like the class.
+    /// For [`UNSUPPORTED_NEGATIVE`](super::UNSUPPORTED_NEGATIVE) and [`UNSUPPORTED_MODIFIER`], the CollisionId of the class
+    /// without its `-` prefix or its modifier.
+    pub group: Option<&'static str>,
+    /// Is a negative style (`-mt-2`).
+    pub negative: bool,
+}
+
+impl fmt::Display for ClassDiagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Range { start, end } = self.span;
+        write!(f, "{start}..{end} `{}`: {}", self.class, self.error)?;
+        if let Some(value) = self.value {
+            write!(f, " `{value}`")?;
+        }
+        if let Some(group) = self.group {
+            write!(f, " ({group})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Merges the Tailwind classes in the string, failing if any class is unknown or can't be parsed.
+///
+/// Uses the [`TwMerger`] in scope, if any. If you need custom options use [`merge_strict_options`].
+///
+/// ```
+/// use tw_merge::merge::*;
+///
+/// assert_eq!(merge_strict("p-2 flex p-4"), Ok("flex p-4".to_string()));
+///
+/// let diagnostics = merge_strict("felx p-4 z-top bg-rde-500").unwrap_err();
+/// assert_eq!(diagnostics[0].to_string(), "0..4 `felx`: Invalid Tailwind class `felx`");
+/// assert_eq!(diagnostics[1].to_string(), "9..14 `z-top`: Invalid Tailwind utility value `top` (z-index)");
+/// assert_eq!(
+///     diagnostics[2].to_string(),
+///     "15..25 `bg-rde-500`: Invalid Tailwind utility value `rde-500` (background-color)"
+/// );
+/// ```
+pub fn merge_strict(class: &str) -> Result<String, Vec<ClassDiagnostic<'_>>> {
+    into_result(merge_lenient(class))
+}
+
+/// Merges the Tailwind classes in the string with the provided options,
+/// failing if any class is unknown or can't be parsed.
+///
+/// If you don't need custom options use [`merge_strict`].
+pub fn merge_strict_options(class: &str, options: MergeOptions) -> Result<String, Vec<ClassDiagnostic<'_>>> {
+    into_result(merge_lenient_options(class, options))
+}
+
+/// Merges the Tailwind classes in the string, returning the unknown classes next to the output.
+///
+/// Unknown classes are kept in the output, same as [`crate::merge::merge_classes`].
+///
+/// Uses the [`TwMerger`] in scope, if any. If you need custom options use [`merge_lenient_options`].
+///
+/// ```
+/// use tw_merge::merge::*;
+///
+/// let (merged, diagnostics) = merge_lenient("p-2 bg-[red p-4");
+/// assert_eq!(merged, "bg-[red p-4");
+/// assert_eq!(diagnostics[0].span, 4..11);
+/// assert_eq!(diagnostics[0].error, INVALID_SYNTAX);
+/// ```
+pub fn merge_lenient(class: &str) -> (String, Vec<ClassDiagnostic<'_>>) {
+    TwMerger::lenient_scoped(&[class]).unwrap_or_else(|| merge_lenient_options(class, Default::default()))
+}
+
+/// Merges the Tailwind classes in the string with the provided options,
+/// returning the unknown classes next to the output.
+///
+/// If you don't need custom options use [`merge_lenient`].
+pub fn merge_lenient_options(class: &str, options: MergeOptions) -> (String, Vec<ClassDiagnostic<'_>>) {
+    let table = CollisionTable::default_ref();
+    lenient_override(&[class], options, table, &|_: &[&str], _: Option<&str>| None, &|_: &str| None)
+}
+
+pub(crate) fn into_result<'a>(
+    (merged, diagnostics): (String, Vec<ClassDiagnostic<'a>>),
+) -> Result<String, Vec<ClassDiagnostic<'a>>> {
+    match diagnostics.is_empty() {
+        true => Ok(merged),
+        false => Err(diagnostics),
+    }
+}
+
+pub(crate) fn lenient_override<'a>(
+    class: &[&'a str],
+    options: MergeOptions,
+    table: &CollisionTable,
+    collision_id_fn: &impl CollisionIdFn,
+    collisions_fn: &impl GetCollisionsFn,
+) -> (String, Vec<ClassDiagnostic<'a>>) {
+    let resolved = resolve(class, options, table, collision_id_fn, collisions_fn);
+    let diagnostics = resolved.iter().filter_map(|resolved| diagnose(resolved, table, collision_id_fn)).collect();
+    let merged = resolved
+        .into_iter()
+        .filter(|resolved| resolved.dropped_by.is_none())
+        .map(|resolved| resolved.source)
+        .collect::<Vec<_>>()
+        .join(" ");
+    (merged, diagnostics)
+}
+
+// Why the class is not a valid Tailwind class, if it isn't.
+fn diagnose<'a>(
+    resolved: &Resolved<'a>,
+    table: &CollisionTable,
+    collision_id_fn: &impl CollisionIdFn,
+) -> Option<ClassDiagnostic<'a>> {
+    let Resolved { source, span, style, collision_id, rejected, .. } = resolved;
+    let (Rejected { error, group }, value) = match (style, collision_id, rejected) {
+        (Some(style), Some(id), _) => {
+            let (group, value) = invalid_color(style, id, table, collision_id_fn)?;
+            (Rejected { error: INVALID_VALUE, group: Some(group) }, Some(value))
+        }
+        (style, _, rejected) => {
+            let rejected = (*rejected)?;
+            let value = style.as_ref().and_then(|style| match rejected.error {
+                UNKNOWN_UTILITY => style.elements.first().copied(),
+                INVALID_VALUE => value_of(style, 1),
+                UNSUPPORTED_MODIFIER => style.modifier,
+                _ => None,
+            });
+            (rejected, value)
+        }
+    };
+    let negative = style.as_ref().is_some_and(|style| style.negative);
+    Some(ClassDiagnostic { class: source, span: span.clone(), error, value, group, negative })
+}
+
+// The group and value of a class which only matched a color group because it accepts anything, `bg-rde-500`.
+// Classes resolved by the theme or a custom CollisionIdFn are trusted.
+fn invalid_color<'a>(
+    style: &AstStyle<'a>,
+    id: &str,
+    table: &CollisionTable,
+    collision_id_fn: &impl CollisionIdFn,
+) -> Option<(&'static str, &'a str)> {
+    let group = *COLOR_GROUPS.iter().find(|group| **group == id)?;
+    if style.arbitrary.is_some()
+        || style.css_variable.is_some()
+        || collision_id_fn.apply(&style.elements, None).is_some()
+    {
+        return None;
+    }
+    let pattern = table.group(group)?.patterns.into_iter().find(|pattern| pattern.matches(&style.elements, ""))?;
+    let utility = pattern.elements.len();
+    match is_color_value(group, &style.elements[utility..]) {
+        true => None,
+        false => Some((group, value_of(style, utility)?)),
+    }
+}
+
+// The value of the class after the utility elements, `top` for `z-top`.
+fn value_of<'a>(style: &AstStyle<'a>, utility: usize) -> Option<&'a str> {
+    let value = style.elements.get(utility..).filter(|value| !value.is_empty());
+    let Some(value) = value else {
+        return style.arbitrary;
+    };
+    // The elements are separated by `-` in the class.
+    let value = value.join("-");
+    let start = style.source.rfind(&value)?;
+    Some(&style.source[start..start + value.len()])
+}
+
+/* ========================================================== */
+/*                       🧪 TESTS 🧪                          */
+/* ========================================================== */
+
+#[test]
+fn values_of_classes() {
+    let options = Default::default();
+    let style = |class| crate::ast::parse_single(class, &options).unwrap();
+    assert_eq!(value_of(&style("hover:!z-top"), 1), Some("top"));
+    assert_eq!(value_of(&style("bg-rde-500/50"), 1), Some("rde-500"));
+    assert_eq!(value_of(&style("z-[top]"), 1), Some("top"));
+    assert_eq!(value_of(&style("z"), 1), None);
+}
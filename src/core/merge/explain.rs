@@ -0,0 +1,156 @@
+use std::fmt;
+
+use super::sort_variants::sort_variants;
+use super::tw_merge_override::{Resolved, resolve};
+use super::{CollisionIdFn, CollisionTable, GetCollisionsFn, MergeOptions, TwMerger};
+
+/// Why each class was kept or dropped by the merger. See [`merge_explain`].
+///
+/// The [`fmt::Display`] output has one line per class, which is handy for snapshot tests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergeExplanation<'a> {
+    /// One entry per input class, in input order.
+    pub classes: Vec<ClassExplanation<'a>>,
+}
+
+/// What the merger did with a single class.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClassExplanation<'a> {
+    /// The class as it appears in the input.
+    pub class: &'a str,
+    /// Whether the class made it into the output.
+    pub status: ClassStatus<'a>,
+    /// The CollisionId of the class, `None` if it is unknown or could not be parsed.
+    pub collision_id: Option<&'a str>,
+    /// Variants used to compare classes, order-insensitive variants are sorted.
+    pub variants: Vec<&'a str>,
+    /// Is a `!important` style. Important classes only collide with each other.
+    pub important: bool,
+    /// Is a negative style (`-mt-2`). Negative classes collide with their positive forms.
+    pub negative: bool,
+}
+
+/// Whether a class was kept or dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClassStatus<'a> {
+    /// The class is in the merged output.
+    Kept,
+    /// The class was knocked out by a later class.
+    Dropped(DroppedBy<'a>),
+}
+
+/// The later class that knocked out a class.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DroppedBy<'a> {
+    /// Index of the class in [`MergeExplanation::classes`].
+    pub index: usize,
+    /// The class as it appears in the input.
+    pub class: &'a str,
+    /// The CollisionId of the later class.
+    ///
+    /// If it differs from the dropped class' CollisionId, the class was dropped through the
+    /// `via -> collision_id` edge from [`crate::merge::GetCollisionsFn`] (e.g. `padding -> padding-x`).
+    pub via: &'a str,
+}
+
+impl<'a> MergeExplanation<'a> {
+    fn new(resolved: Vec<Resolved<'a>>) -> Self {
+        let sources: Vec<&str> = resolved.iter().map(|resolved| resolved.source).collect();
+        let classes = resolved
+            .into_iter()
+            .map(|Resolved { source, style, collision_id, dropped_by, .. }| ClassExplanation {
+                class: source,
+                status: match dropped_by {
+                    Some((index, via)) => ClassStatus::Dropped(DroppedBy { index, class: sources[index], via }),
+                    None => ClassStatus::Kept,
+                },
+                collision_id,
+                variants: style.as_ref().map(|style| sort_variants(&style.variants)).unwrap_or_default(),
+                important: style.as_ref().is_some_and(|style| style.important),
+                negative: style.is_some_and(|style| style.negative),
+            })
+            .collect();
+        Self { classes }
+    }
+
+    /// The merged classes, same as the output of the merger.
+    pub fn merged(&self) -> String {
+        self.classes
+            .iter()
+            .filter(|class| class.status == ClassStatus::Kept)
+            .map(|class| class.class)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Only the classes that were dropped.
+    pub fn dropped(&self) -> impl Iterator<Item = &ClassExplanation<'a>> {
+        self.classes.iter().filter(|class| class.status != ClassStatus::Kept)
+    }
+}
+
+impl fmt::Display for MergeExplanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, class) in self.classes.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{class}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ClassExplanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let collision_id = self.collision_id.unwrap_or("unknown");
+        match self.status {
+            ClassStatus::Kept => write!(f, "{}: kept ({collision_id})", self.class),
+            ClassStatus::Dropped(DroppedBy { class, via, .. }) if via == collision_id => {
+                write!(f, "{}: dropped by {class} ({collision_id})", self.class)
+            }
+            ClassStatus::Dropped(DroppedBy { class, via, .. }) => {
+                write!(f, "{}: dropped by {class} ({via} -> {collision_id})", self.class)
+            }
+        }
+    }
+}
+
+/// Explains how the Tailwind classes in the string are merged.
+///
+/// Uses the [`TwMerger`] in scope, if any. If you need custom options use [`merge_explain_options`].
+///
+/// ```
+/// use tw_merge::merge::*;
+///
+/// let explanation = merge_explain("px-2 hover:p-2 p-4 flex");
+/// assert_eq!(explanation.merged(), "hover:p-2 p-4 flex");
+/// assert_eq!(
+///     explanation.to_string(),
+///     "px-2: dropped by p-4 (padding -> padding-x)\n\
+///      hover:p-2: kept (padding)\n\
+///      p-4: kept (padding)\n\
+///      flex: kept (display)"
+/// );
+/// ```
+pub fn merge_explain(class: &str) -> MergeExplanation<'_> {
+    TwMerger::explain_scoped(&[class]).unwrap_or_else(|| merge_explain_options(class, Default::default()))
+}
+
+/// Explains how the Tailwind classes in the string are merged, with the provided options.
+///
+/// If you don't need custom options use [`merge_explain`].
+pub fn merge_explain_options(class: &str, options: MergeOptions) -> MergeExplanation<'_> {
+    let table = CollisionTable::default_ref();
+    explain_override(&[class], options, table, &|_: &[&str], _: Option<&str>| None, &|_: &str| None)
+}
+
+pub(crate) fn explain_override<'a>(
+    class: &[&'a str],
+    options: MergeOptions,
+    table: &CollisionTable,
+    collision_id_fn: &impl CollisionIdFn,
+    collisions_fn: &impl GetCollisionsFn,
+) -> MergeExplanation<'a> {
+    MergeExplanation::new(resolve(class, options, table, collision_id_fn, collisions_fn))
+}
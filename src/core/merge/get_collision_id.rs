@@ -1,960 +1,1021 @@
+use super::collision_table::Element::{Any, Is, OneOf, Value};
+use super::collision_table::{Arbitrary, ClassPattern, Element, Rest};
 use super::validators;
 
-pub type Result<T> = std::result::Result<T, &'static str>;
-
-pub fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static str> {
-    match classes {
-        // https://tailwindcss.com/docs/aspect-ratio
-        ["aspect", "auto" | "square" | "video"] => Ok("aspect"),
-        ["aspect", n] if parse_fraction(n).is_some() => Ok("aspect"),
-        ["aspect"] if parse_decimal_fraction(arbitrary) => Ok("aspect"), 
-        // https://tailwindcss.com/docs/container
-        ["container"] => Ok("container"),
-
-        // v4: https://tailwindcss.com/docs/container-queries
-        // @container, @container/name (named containers)
-        ["@container"] => Ok("@container"),
-        [first, ..] if first.starts_with("@container/") || first.starts_with("@container") => Ok("@container"),
-
-        // https://tailwindcss.com/docs/columns
-        ["columns", "auto"] => Ok("columns"),
-        ["columns", rest] if is_t_shirt_size(rest) || rest.parse::<usize>().is_ok() => {
-            Ok("columns")
-        }
-        ["columns"] if is_arbitrary_len(arbitrary) => {
-            Ok("columns")
-        }
-        // https://tailwindcss.com/docs/break-after
-        ["break", "after", rest] if valid_break_after(rest) => Ok("break-after"),
-
-        // https://tailwindcss.com/docs/break-before
-        ["break", "before", rest] if valid_break_after(rest) => {
-            Ok("break-before")
-        }
-        // https://tailwindcss.com/docs/break-inside
-        ["break", "inside", rest] => {
-            if valid_break_after(rest) {
-                Ok("break-inside")
-            } else {
-                Err("Invalid break-inside")
-            }
-        }
-        // https://tailwindcss.com/docs/box-decoration-break
-        ["box", "decoration", "clone" | "slice"] => {
-            Ok("box-decoration-break")
-        }
-        // https://tailwindcss.com/docs/box-sizing
-        ["box", "border" | "content"] => Ok("box-sizing"),
-
-        // https://tailwindcss.com/docs/display
-        ["block"]
-        | ["inline", "block"]
-        | ["inline"]
-        | ["flex"]
-        | ["inline","flex"]
-        | ["table"]
-        | ["inline","table"]
-        | ["table","caption"]
-        | ["table","cell"]
-        | ["table","column"]
-        | ["table","column","group"]
-        | ["table","footer","group"]
-        | ["table","header","group"]
-        | ["table","row","group"]
-        | ["table","row"]
-        | ["flow","root"]
-        | ["grid"]
-        | ["inline","grid"]
-        | ["contents"]
-        | ["hidden"]
-            if arbitrary.is_empty() =>
-        {
-            Ok("display")
-        }
-
-        // https://tailwindcss.com/docs/float
-        ["float", "start" | "end" | "right" | "none"] => {
-            Ok("float")
-        }
-
-        // https://tailwindcss.com/docs/clear
-        ["clear", "start" | "end" | "right" | "both" | "none"] => Ok("clear"),
-
-        // https://tailwindcss.com/docs/isolation
-        ["isolation"] | ["isolation", "auto"] => Ok("isolation"),
-
-        // https://tailwindcss.com/docs/object-fit
-        ["object", "contain"]
-        | ["object", "cover"]
-        | ["object", "fill"]
-        | ["object", "none"]
-        | ["object", "scale", "down"] => Ok("object-fit"),
-
-        // https://tailwindcss.com/docs/object-position
-        ["object", "bottom"]
-        | ["object", "center"]
-        | ["object", "left"]
-        | ["object", "left", "bottom"]
-        | ["object", "left", "top"]
-        | ["object", "right"]
-        | ["object", "right", "bottom"]
-        | ["object", "right", "top"]
-        | ["object", "top"] => Ok("object-position"),
-
-        // https://tailwindcss.com/docs/overflow
-        ["overflow", "auto"]
-        | ["overflow", "hidden"]
-        | ["overflow", "clip"]
-        | ["overflow", "visible"]
-        | ["overflow", "scroll"]
-        | ["overflow", "x", "auto"]
-        | ["overflow", "y", "auto"]
-        | ["overflow","x", "hidden"]
-        | ["overflow","y", "hidden"]
-        | ["overflow", "x", "clip"]
-        | ["overflow", "y","clip"]
-        | ["overflow","x", "visible"]
-        | ["overflow", "y", "visible"]
-        | ["overflow","x", "scroll"]
-        | ["overflow","y", "scroll"] => Ok("overflow"),
-
-        // https://tailwindcss.com/docs/overscroll-behavior
-        ["overscroll", "auto"]
-        | ["overscroll", "contain"]
-        | ["overscroll", "none"]
-        | ["overscroll", "y", "auto"]
-        | ["overscroll", "y", "contain"]
-        | ["overscroll", "y", "none"]
-        | ["overscroll", "x", "auto"]
-        | ["overscroll", "x", "contain"]
-        | ["overscroll", "x", "none"] => Ok("overscroll-behavior"),
-
-        // https://tailwindcss.com/docs/position
-        ["static"] | ["fixed"] | ["absolute"] | ["relative"] | ["sticky"] => Ok("position"),
-
-        // v4: https://tailwindcss.com/docs/box-shadow#adding-an-inset-shadow
-        ["inset", "shadow", "none"] => Ok("inset-shadow"),
-        ["inset", "shadow", size] if is_t_shirt_size(size) => Ok("inset-shadow"),
-        ["inset", "shadow"] if arbitrary.is_empty() => Ok("inset-shadow"),
-        ["inset", "shadow", ..] => Ok("inset-shadow-color"),
-
-        // v4: https://tailwindcss.com/docs/ring-width#adding-an-inset-ring
-        ["inset", "ring", rest] if rest.parse::<usize>().is_ok() => Ok("inset-ring"),
-        ["inset", "ring"] if arbitrary.is_empty() || arbitrary.parse::<usize>().is_ok() => Ok("inset-ring"),
-        ["inset", "ring", ..] => Ok("inset-ring-color"),
-
-        // https://tailwindcss.com/docs/top-right-bottom-left
-        ["inset", "x", rest @ ..] => valid_trbl(rest, arbitrary, "inset-x", "Invalid inset-x"),
-        ["inset", "y", rest @ ..] => valid_trbl(rest, arbitrary, "inset-y", "Invalid inset-y"),
-        // https://tailwindcss.com/docs/top-right-bottom-left#using-logical-properties
-        ["inset", "inline", "start", rest @ ..] => valid_trbl(rest, arbitrary, "start", "Invalid inset-inline-start"),
-        ["inset", "inline", "end", rest @ ..] => valid_trbl(rest, arbitrary, "end", "Invalid inset-inline-end"),
-        ["inset", "inline", rest @ ..] => valid_trbl(rest, arbitrary, "inset-x", "Invalid inset-inline"),
-        ["inset", "block", "start", rest @ ..] => valid_trbl(rest, arbitrary, "top", "Invalid inset-block-start"),
-        ["inset", "block", "end", rest @ ..] => valid_trbl(rest, arbitrary, "bottom", "Invalid inset-block-end"),
-        ["inset", "block", rest @ ..] => valid_trbl(rest, arbitrary, "inset-y", "Invalid inset-block"),
-        ["inset", rest @ ..] => valid_trbl(rest, arbitrary, "inset", "Invalid inset"),
-        ["top", rest @ ..] => valid_trbl(rest, arbitrary, "top", "Invalid top"),
-        ["right", rest @ ..] => valid_trbl(rest, arbitrary, "right", "Invalid right"),
-        ["bottom", rest @ ..] => valid_trbl(rest, arbitrary, "bottom", "Invalid bottom"),
-        ["left", rest @ ..] => valid_trbl(rest, arbitrary, "left", "Invalid left"),
-        ["start", rest @ ..] => valid_trbl(rest, arbitrary, "start", "Invalid start"),
-        ["end", rest @ ..] => valid_trbl(rest, arbitrary, "end", "Invalid end"),
-
-        // https://tailwindcss.com/docs/visibility
-        ["visible" | "invisible" | "collapse"] => Ok("visibility"),
-
-        // https://tailwindcss.com/docs/z-index
-        ["z", "auto"] => Ok("z-index"),
-        ["z", index] => match index.parse::<usize>().ok() {
-            Some(_) => Ok("z-index"),
-            None => Err("Invalid z index"),
-        },
-        ["z"] => match arbitrary.parse::<usize>().ok() {
-            Some(_) => Ok("z-index"),
-            None => Err("Invalid z index"),
-        },
-
-        // https://tailwindcss.com/docs/flex-basis
-        ["basis", "full" | "auto" | "px" ] => Ok("flex-basis"),
-        ["basis", rest] => {
-            if parse_fraction_or_usize(rest) {
-                Ok("flex-basis")
-            } else {
-                Err("Invalid flex-basis")
-            }
-        }
-        ["basis"] => {
-            if parse_fraction_or_usize(arbitrary) {
-                Ok("flex-basis")
-            } else {
-                Err("Invalid flex-basis")
-            }
-        }
-
-        // https://tailwindcss.com/docs/flex-direction
-        ["flex", "row"]
-        | ["flex", "row", "reverse"]
-        | ["flex", "col"]
-        | ["flex", "col", "reverse"] => Ok("flex-direction"),
-
-        // https://tailwindcss.com/docs/flex-wrap
-        ["flex", "wrap"] | ["flex", "wrap", "reverse"] | ["flex", "nowrap"] => Ok("flex-wrap"),
-
-        // https://tailwindcss.com/docs/flex-grow
-        // Legacy: flex-grow-0, flex-grow-[n] (deprecated in favor of grow-*)
-        // Must be before generic ["flex", _] pattern
-        ["flex", "grow", ..] => Ok("flex-grow"),
-
-        // https://tailwindcss.com/docs/flex-shrink
-        // Legacy: flex-shrink-0, flex-shrink-[n] (deprecated in favor of shrink-*)
-        // Must be before generic ["flex", _] pattern
-        ["flex", "shrink", ..] => Ok("flex-shrink"),
-
-        // https://tailwindcss.com/docs/flex
-        ["flex", "1"] | ["flex", "auto"] | ["flex", "initial"] | ["flex", "none"] => Ok("flex"),
-        // TODO: check this?
-        ["flex", _] => Ok("flex"),
-        ["flex"] if !arbitrary.is_empty() => Ok("flex"),
-
-        // https://tailwindcss.com/docs/flex-grow
-        ["grow", ..] => Ok("flex-grow"),
-
-        // https://tailwindcss.com/docs/flex-shrink
-        ["shrink", ..] => Ok("flex-shrink"),
-
-        // https://tailwindcss.com/docs/order
-        ["order", "first" | "last" | "none"] => Ok("order"),
-        ["order", rest] if rest.parse::<isize>().is_ok() => Ok("order"),
-        ["order"] if arbitrary.parse::<isize>().is_ok() => Ok("order"),
-
-        // https://tailwindcss.com/docs/grid-template-columns
-        ["grid", "cols", ..] => Ok("grid-template-columns"),
-
-        // https://tailwindcss.com/docs/grid-column
-        ["col", "auto"] | ["col", "span", ..] => Ok("col-start-end"),
-        ["col", "start", ..] => Ok("col-start"),
-        ["col", "end", ..] => Ok("col-end"),
-
-        // https://tailwindcss.com/docs/grid-template-rows
-        ["grid", "rows", ..] => Ok("grid-template-rows"),
-
-        // https://tailwindcss.com/docs/grid-row
-        ["row", "auto"] | ["row", "span", ..] => Ok("row-start-end"),
-        ["row", "start", ..] => Ok("row-start"),
-        ["row", "end", ..] => Ok("row-end"),
-
-        // https://tailwindcss.com/docs/grid-auto-flow
-        ["grid", "flow", "row"]
-        | ["grid", "flow", "col"]
-        | ["grid", "flow", "dense"]
-        | ["grid", "flow", "row", "dense"]
-        | ["grid", "flow", "col", "dense"] => Ok("grid-auto-flow"),
-
-        // https://tailwindcss.com/docs/grid-auto-columns
-        ["auto", "cols", ..] => Ok("auto-cols"),
-
-        // https://tailwindcss.com/docs/grid-auto-rows
-        ["auto", "rows", ..] => Ok("auto-rows"),
-
-        // https://tailwindcss.com/docs/gap
-        ["gap", "x", ..] => Ok("gap-x"),
-        ["gap", "y", ..] => Ok("gap-y"),
-        ["gap", ..] => Ok("gap"),
-
-        // https://tailwindcss.com/docs/justify-content
-        // v4.1: safe alignment variants (justify-center-safe, etc.)
-        ["justify", "normal" | "start" | "end" | "center" | "between" | "around" | "evenly" | "stretch"] => Ok("justify-content"),
-        ["justify", "center" | "start" | "end", "safe"] => Ok("justify-content"),
-        // https://tailwindcss.com/docs/justify-items
-        ["justify", "items", "start" | "end" | "center" | "stretch"] => Ok("justify-items"),
-        ["justify", "items", "center" | "start" | "end", "safe"] => Ok("justify-items"),
-
-        // https://tailwindcss.com/docs/justify-self
-        ["justify", "self", "start" | "end" | "center" | "stretch"] => Ok("justify-self"),
-        ["justify", "self", "center" | "start" | "end", "safe"] => Ok("justify-self"),
-
-        // https://tailwindcss.com/docs/align-content
-        ["content", "normal" | "center" | "start" | "end" | "between" | "around" | "evenly" | "baseline" | "stretch"] => Ok("align-content"),
-        ["content", "center" | "start" | "end", "safe"] => Ok("align-content"),
-
-        // https://tailwindcss.com/docs/align-items
-        // v4.1: items-baseline-last
-        ["items", "start" | "end" | "center" | "baseline" | "stretch"] => Ok("align-items"),
-        ["items", "center" | "start" | "end", "safe"] => Ok("align-items"),
-        ["items", "baseline", "last"] => Ok("align-items"),
-
-        // https://tailwindcss.com/docs/align-self
-        // v4.1: self-baseline-last
-        ["self", "auto" | "start" | "end" | "center" | "stretch" | "baseline"] => Ok("align-self"),
-        ["self", "center" | "start" | "end", "safe"] => Ok("align-self"),
-        ["self", "baseline", "last"] => Ok("align-self"),
-
-        // https://tailwindcss.com/docs/place-content
-        ["place", "content", "center" | "start" | "end" | "between" | "around" | "evenly" | "baseline" | "stretch"] => Ok("place-content"),
-        ["place", "content", "center" | "start" | "end", "safe"] => Ok("place-content"),
-
-        // https://tailwindcss.com/docs/place-items
-        ["place", "items", "start" | "end" | "center" | "baseline" | "stretch"] => Ok("place-items"),
-        ["place", "items", "center" | "start" | "end", "safe"] => Ok("place-items"),
-
-        // https://tailwindcss.com/docs/place-self
-        ["place", "self", "start" | "end" | "center" | "baseline" | "stretch"] => Ok("place-self"),
-        ["place", "self", "center" | "start" | "end", "safe"] => Ok("place-self"),
-
-        // https://tailwindcss.com/docs/padding
-        ["p", ..] => Ok("padding"),
-        ["pl", ..] => Ok("padding-left"),
-        ["pr", ..] => Ok("padding-right"),
-        ["pt", ..] => Ok("padding-top"),
-        ["pb", ..] => Ok("padding-bottom"),
-        ["px", ..] => Ok("padding-x"),
-        ["py", ..] => Ok("padding-y"),
-
-        // https:: //tailwindcss.com/docs/margin
-        ["m", ..] => Ok("margin"),
-        ["ml", ..] => Ok("margin-left"),
-        ["mr", ..] => Ok("margin-right"),
-        ["mt", ..] => Ok("margin-top"),
-        ["mb", ..] => Ok("margin-bottom"),
-        ["mx", ..] => Ok("margin-x"),
-        ["my", ..] => Ok("margin-y"),
-        ["ms", ..] => Ok("margin-start"),
-        ["me", ..] => Ok("margin-end"),
-
-        // https://tailwindcss.com/docs/space
-        ["space", "x", "reverse"] => Ok("space-x-reverse"),
-        ["space", "y", "reverse"] => Ok("space-y-reverse"),
-        ["space", "x", ..] => Ok("space-x"),
-        ["space", "y", ..] => Ok("space-y"),
-
-        // https://tailwindcss.com/docs/width
-        // TODO: Add validation?
-        ["w", ..] => Ok("width"),
-
-        // https://tailwindcss.com/docs/min-width
-        // TODO: Add validation?
-        ["min", "w", ..] => Ok("min-width"),
-
-        // https://tailwindcss.com/docs/max-width
-        ["max", "w", ..] => Ok("max-width"),
-
-        // https://tailwindcss.com/docs/height
-        ["h", ..] => Ok("height"),
-
-        // https://tailwindcss.com/docs/min-height
-        ["min", "h", ..] => Ok("min-height"),
-
-        // https://tailwindcss.com/docs/max-height
-        ["max", "h", ..] => Ok("max-height"),
-
-        // https://tailwindcss.com/docs/size
-        ["size", ..] => Ok("size"),
-
-        // https://tailwindcss.com/docs/font-family
-        // TODO: This clash is bad
-        ["font", "sans"] | ["font", "serif"] | ["font", "mono"] => Ok("font-family"),
-
-        // https://tailwindcss.com/docs/text-align
-        ["text", "left" | "center" | "right" | "justify" | "start" | "end"] => Ok("text-align"),
-
-        // https://tailwindcss.com/docs/text-overflow
-        ["text", "ellipsis" | "clip"] => Ok("text-overflow"),
-
-        // https://tailwindcss.com/docs/text-wrap
-        ["text", "wrap" | "nowrap" | "balance" | "pretty"] => Ok("text-wrap"),
-
-        // v4.1: https://tailwindcss.com/docs/overflow-wrap
-        ["wrap", "break", "word"] => Ok("overflow-wrap"),
-        ["wrap", "anywhere"] => Ok("overflow-wrap"),
-        ["wrap", "normal"] => Ok("overflow-wrap"),
-
-        // https://tailwindcss.com/docs/font-size
-        ["text", rest] if valid_text_size(rest) => Ok("font-size"),
-        ["text"] if is_arbitrary_len(arbitrary) => Ok("font-size"),
-
-        // v4: https://tailwindcss.com/docs/text-shadow
-        ["text", "shadow", "none"] => Ok("text-shadow"),
-        ["text", "shadow", size] if is_t_shirt_size(size) => Ok("text-shadow"),
-        ["text", "shadow"] => Ok("text-shadow"),
-        ["text", "shadow", ..] => Ok("text-shadow-color"),
-
-        // https://tailwindcss.com/docs/text-color
-        ["text", ..] => Ok("text-color"),
-
-        // https://tailwindcss.com/docs/font-smoothing
-        ["antialiased"] | ["subpixel", "antialiased"] => Ok("font-smoothing"),
-
-        // https://tailwindcss.com/docs/font-style
-        ["italic"] | ["not","italic"] => Ok("font-style"),
-
-        // v4: https://tailwindcss.com/docs/font-stretch
-        ["font", "stretch", ..] => Ok("font-stretch"),
-
-        // https://tailwindcss.com/docs/font-weight
-        ["font", ..] => Ok("font-weight"),
-
-        // https://tailwindcss.com/docs/font-variant-numeric
-        ["normal", "nums"] => Ok("fvn-normal"),
-        ["ordinal"] => Ok("fvn-ordinal"),
-        ["slashed", "zero"] => Ok("fvn-slashed-zero"),
-        ["lining", "nums"] | ["oldstyle", "nums"] => Ok("fvn-figure"),
-        ["proportional", "nums"] | ["tabular","nums"] => Ok("fvn-spacing"),
-        ["diagonal", "fractions"] | ["stacked","fractions"] => Ok("fvn-fraction"),
-
-        // https://tailwindcss.com/docs/letter-spacing
-        ["tracking", ..] => Ok("letter-spacing"),
-
-        // https://tailwindcss.com/docs/line-clamp
-        ["line", "clamp", ..] => Ok("line-clamp"),
-
-        // https://tailwindcss.com/docs/line-height
-        ["leading", ..] => Ok("line-height"),
-
-        // https://tailwindcss.com/docs/list-style-image
-        ["list", "image", ..] => Ok("list-style-image"),
-
-        // https://tailwindcss.com/docs/list-style-position
-        ["list", "inside"] | ["list", "outside"] => Ok("list-style-position"),
-
-        // https://tailwindcss.com/docs/list-style-type
-        ["list", ..] => Ok("list-style-type"),
-
-        // https://tailwindcss.com/docs/text-decoration
-        ["underline"] | ["overline"] | ["line", "through"] | ["no", "underline"] => Ok("text-decoration"),
-
-        // https://tailwindcss.com/docs/text-decoration-style
-        ["decoration", "solid" | "double" | "dotted" | "dashed" | "wavy"] => Ok("text-decoration-style"),
+// ["p"]
+const fn exact(elements: &'static [Element]) -> ClassPattern {
+    ClassPattern::new(elements)
+}
 
-        // https://tailwindcss.com/docs/text-decoration-thickness
-        ["decoration", "auto"] | ["decoration", "from-font"] => Ok("text-decoration-thickness"),
-        ["decoration", rest] => {
-            if rest.parse::<usize>().is_ok() {
-                Ok("text-decoration-thickness")
-            } else {
-                Err("Invalid text-decoration-thickness")
-            }
-        }
-        ["decoration"] if arbitrary.parse::<usize>().is_ok() => Ok("text-decoration-thickness"),
+// ["p", ..]
+const fn prefix(elements: &'static [Element]) -> ClassPattern {
+    ClassPattern::new(elements).with_rest(Rest::Any)
+}
 
-        // https://tailwindcss.com/docs/text-decoration-color
-        ["decoration", ..] => Ok("text-decoration-color"),
+// ["p"] if validator(arbitrary)
+const fn valid(validator: fn(&str) -> bool) -> Arbitrary {
+    Arbitrary::Value(validator)
+}
 
-        // https://tailwindcss.com/docs/text-underline-offset
-        ["underline", "offset", ..] => Ok("text-underline-offset"),
+const NO_ARBITRARY: Arbitrary = Arbitrary::Empty;
+
+/// The default class groups, the first matching pattern wins.
+#[rustfmt::skip]
+pub(crate) static RULES: &[(&str, ClassPattern)] = &[
+    // https://tailwindcss.com/docs/aspect-ratio
+    ("aspect", exact(&[Is("aspect"), OneOf(&["auto", "square", "video"])])),
+    ("aspect", exact(&[Is("aspect"), Value(is_fraction)])),
+    ("aspect", exact(&[Is("aspect")]).with_arbitrary(valid(parse_decimal_fraction))),
+    // https://tailwindcss.com/docs/container
+    ("container", exact(&[Is("container")])),
+
+    // v4: https://tailwindcss.com/docs/container-queries
+    // @container, @container/name (named containers)
+    ("@container", prefix(&[Value(|first| first.starts_with("@container"))])),
+
+    // https://tailwindcss.com/docs/columns
+    ("columns", exact(&[Is("columns"), Is("auto")])),
+    ("columns", exact(&[Is("columns"), Value(|rest| is_t_shirt_size(rest) || is_usize(rest))])),
+    ("columns", exact(&[Is("columns")]).with_arbitrary(valid(is_arbitrary_len))),
+
+    // https://tailwindcss.com/docs/break-after
+    ("break-after", exact(&[Is("break"), Is("after"), Value(valid_break_after)])),
+
+    // https://tailwindcss.com/docs/break-before
+    ("break-before", exact(&[Is("break"), Is("before"), Value(valid_break_after)])),
+
+    // https://tailwindcss.com/docs/break-inside
+    ("break-inside", exact(&[Is("break"), Is("inside"), Value(valid_break_after)])),
+
+    // https://tailwindcss.com/docs/box-decoration-break
+    ("box-decoration-break", exact(&[Is("box"), Is("decoration"), OneOf(&["clone", "slice"])])),
+
+    // https://tailwindcss.com/docs/box-sizing
+    ("box-sizing", exact(&[Is("box"), OneOf(&["border", "content"])])),
+
+    // https://tailwindcss.com/docs/display
+    ("display", exact(&[OneOf(&["block", "inline", "flex", "table", "grid", "contents", "hidden"])]).with_arbitrary(NO_ARBITRARY)),
+    ("display", exact(&[Is("inline"), OneOf(&["block", "flex", "table", "grid"])]).with_arbitrary(NO_ARBITRARY)),
+    ("display", exact(&[Is("table"), OneOf(&["caption", "cell", "column", "row"])]).with_arbitrary(NO_ARBITRARY)),
+    ("display", exact(&[Is("table"), OneOf(&["column", "footer", "header", "row"]), Is("group")]).with_arbitrary(NO_ARBITRARY)),
+    ("display", exact(&[Is("flow"), Is("root")]).with_arbitrary(NO_ARBITRARY)),
+
+    // https://tailwindcss.com/docs/float
+    ("float", exact(&[Is("float"), OneOf(&["start", "end", "right", "none"])])),
+
+    // https://tailwindcss.com/docs/clear
+    ("clear", exact(&[Is("clear"), OneOf(&["start", "end", "right", "both", "none"])])),
+
+    // https://tailwindcss.com/docs/isolation
+    ("isolation", exact(&[Is("isolation")])),
+    ("isolation", exact(&[Is("isolation"), Is("auto")])),
+
+    // https://tailwindcss.com/docs/object-fit
+    ("object-fit", exact(&[Is("object"), OneOf(&["contain", "cover", "fill", "none"])])),
+    ("object-fit", exact(&[Is("object"), Is("scale"), Is("down")])),
+
+    // https://tailwindcss.com/docs/object-position
+    ("object-position", exact(&[Is("object"), OneOf(&["bottom", "center", "left", "right", "top"])])),
+    ("object-position", exact(&[Is("object"), OneOf(&["left", "right"]), OneOf(&["bottom", "top"])])),
+
+    // https://tailwindcss.com/docs/overflow
+    ("overflow", exact(&[Is("overflow"), OneOf(&["auto", "hidden", "clip", "visible", "scroll"])])),
+    ("overflow", exact(&[Is("overflow"), OneOf(&["x", "y"]), OneOf(&["auto", "hidden", "clip", "visible", "scroll"])])),
+
+    // https://tailwindcss.com/docs/overscroll-behavior
+    ("overscroll-behavior", exact(&[Is("overscroll"), OneOf(&["auto", "contain", "none"])])),
+    ("overscroll-behavior", exact(&[Is("overscroll"), OneOf(&["x", "y"]), OneOf(&["auto", "contain", "none"])])),
+
+    // https://tailwindcss.com/docs/position
+    ("position", exact(&[OneOf(&["static", "fixed", "absolute", "relative", "sticky"])])),
+
+    // v4: https://tailwindcss.com/docs/box-shadow#adding-an-inset-shadow
+    ("inset-shadow", exact(&[Is("inset"), Is("shadow"), Is("none")])),
+    ("inset-shadow", exact(&[Is("inset"), Is("shadow"), Value(is_t_shirt_size)])),
+    ("inset-shadow", exact(&[Is("inset"), Is("shadow")]).with_arbitrary(NO_ARBITRARY)),
+    ("inset-shadow-color", prefix(&[Is("inset"), Is("shadow")])),
+
+    // v4: https://tailwindcss.com/docs/ring-width#adding-an-inset-ring
+    ("inset-ring", exact(&[Is("inset"), Is("ring"), Value(is_usize)])),
+    ("inset-ring", exact(&[Is("inset"), Is("ring")]).with_arbitrary(valid(|arbitrary| arbitrary.is_empty() || is_usize(arbitrary)))),
+    ("inset-ring-color", prefix(&[Is("inset"), Is("ring")])),
+
+    // https://tailwindcss.com/docs/top-right-bottom-left
+    ("inset-x", exact(&[Is("inset"), Is("x"), Value(valid_top_right_bottom_left)])),
+    ("inset-x", prefix(&[Is("inset"), Is("x")]).with_arbitrary(valid(is_valid_length))),
+    ("inset-y", exact(&[Is("inset"), Is("y"), Value(valid_top_right_bottom_left)])),
+    ("inset-y", prefix(&[Is("inset"), Is("y")]).with_arbitrary(valid(is_valid_length))),
+    // https://tailwindcss.com/docs/top-right-bottom-left#using-logical-properties
+    ("start", exact(&[Is("inset"), Is("inline"), Is("start"), Value(valid_top_right_bottom_left)])),
+    ("start", prefix(&[Is("inset"), Is("inline"), Is("start")]).with_arbitrary(valid(is_valid_length))),
+    ("end", exact(&[Is("inset"), Is("inline"), Is("end"), Value(valid_top_right_bottom_left)])),
+    ("end", prefix(&[Is("inset"), Is("inline"), Is("end")]).with_arbitrary(valid(is_valid_length))),
+    ("inset-x", exact(&[Is("inset"), Is("inline"), Value(valid_top_right_bottom_left)])),
+    ("inset-x", prefix(&[Is("inset"), Is("inline")]).with_arbitrary(valid(is_valid_length))),
+    ("top", exact(&[Is("inset"), Is("block"), Is("start"), Value(valid_top_right_bottom_left)])),
+    ("top", prefix(&[Is("inset"), Is("block"), Is("start")]).with_arbitrary(valid(is_valid_length))),
+    ("bottom", exact(&[Is("inset"), Is("block"), Is("end"), Value(valid_top_right_bottom_left)])),
+    ("bottom", prefix(&[Is("inset"), Is("block"), Is("end")]).with_arbitrary(valid(is_valid_length))),
+    ("inset-y", exact(&[Is("inset"), Is("block"), Value(valid_top_right_bottom_left)])),
+    ("inset-y", prefix(&[Is("inset"), Is("block")]).with_arbitrary(valid(is_valid_length))),
+    ("inset", exact(&[Is("inset"), Value(valid_top_right_bottom_left)])),
+    ("inset", prefix(&[Is("inset")]).with_arbitrary(valid(is_valid_length))),
+    ("top", exact(&[Is("top"), Value(valid_top_right_bottom_left)])),
+    ("top", prefix(&[Is("top")]).with_arbitrary(valid(is_valid_length))),
+    ("right", exact(&[Is("right"), Value(valid_top_right_bottom_left)])),
+    ("right", prefix(&[Is("right")]).with_arbitrary(valid(is_valid_length))),
+    ("bottom", exact(&[Is("bottom"), Value(valid_top_right_bottom_left)])),
+    ("bottom", prefix(&[Is("bottom")]).with_arbitrary(valid(is_valid_length))),
+    ("left", exact(&[Is("left"), Value(valid_top_right_bottom_left)])),
+    ("left", prefix(&[Is("left")]).with_arbitrary(valid(is_valid_length))),
+    ("start", exact(&[Is("start"), Value(valid_top_right_bottom_left)])),
+    ("start", prefix(&[Is("start")]).with_arbitrary(valid(is_valid_length))),
+    ("end", exact(&[Is("end"), Value(valid_top_right_bottom_left)])),
+    ("end", prefix(&[Is("end")]).with_arbitrary(valid(is_valid_length))),
+
+    // https://tailwindcss.com/docs/visibility
+    ("visibility", exact(&[OneOf(&["visible", "invisible", "collapse"])])),
+
+    // https://tailwindcss.com/docs/z-index
+    ("z-index", exact(&[Is("z"), Is("auto")])),
+    ("z-index", exact(&[Is("z"), Value(is_usize)])),
+    ("z-index", exact(&[Is("z")]).with_arbitrary(valid(is_usize))),
+
+    // https://tailwindcss.com/docs/flex-basis
+    ("flex-basis", exact(&[Is("basis"), OneOf(&["full", "auto", "px"])])),
+    ("flex-basis", exact(&[Is("basis"), Value(parse_fraction_or_usize)])),
+    ("flex-basis", exact(&[Is("basis")]).with_arbitrary(valid(parse_fraction_or_usize))),
+
+    // https://tailwindcss.com/docs/flex-direction
+    ("flex-direction", exact(&[Is("flex"), OneOf(&["row", "col"])])),
+    ("flex-direction", exact(&[Is("flex"), OneOf(&["row", "col"]), Is("reverse")])),
+
+    // https://tailwindcss.com/docs/flex-wrap
+    ("flex-wrap", exact(&[Is("flex"), OneOf(&["wrap", "nowrap"])])),
+    ("flex-wrap", exact(&[Is("flex"), Is("wrap"), Is("reverse")])),
+
+    // https://tailwindcss.com/docs/flex-grow
+    // Legacy: flex-grow-0, flex-grow-[n] (deprecated in favor of grow-*)
+    // Must be before generic ["flex", _] pattern
+    ("flex-grow", prefix(&[Is("flex"), Is("grow")])),
+
+    // https://tailwindcss.com/docs/flex-shrink
+    // Legacy: flex-shrink-0, flex-shrink-[n] (deprecated in favor of shrink-*)
+    // Must be before generic ["flex", _] pattern
+    ("flex-shrink", prefix(&[Is("flex"), Is("shrink")])),
+
+    // https://tailwindcss.com/docs/flex
+    // TODO: check this?
+    ("flex", exact(&[Is("flex"), Any])),
+    ("flex", exact(&[Is("flex")]).with_arbitrary(valid(|arbitrary| !arbitrary.is_empty()))),
+
+    // https://tailwindcss.com/docs/flex-grow
+    ("flex-grow", prefix(&[Is("grow")])),
+
+    // https://tailwindcss.com/docs/flex-shrink
+    ("flex-shrink", prefix(&[Is("shrink")])),
+
+    // https://tailwindcss.com/docs/order
+    ("order", exact(&[Is("order"), OneOf(&["first", "last", "none"])])),
+    ("order", exact(&[Is("order"), Value(is_isize)])),
+    ("order", exact(&[Is("order")]).with_arbitrary(valid(is_isize))),
+
+    // https://tailwindcss.com/docs/grid-template-columns
+    ("grid-template-columns", prefix(&[Is("grid"), Is("cols")])),
+
+    // https://tailwindcss.com/docs/grid-column
+    ("col-start-end", exact(&[Is("col"), Is("auto")])),
+    ("col-start-end", prefix(&[Is("col"), Is("span")])),
+    ("col-start", prefix(&[Is("col"), Is("start")])),
+    ("col-end", prefix(&[Is("col"), Is("end")])),
+
+    // https://tailwindcss.com/docs/grid-template-rows
+    ("grid-template-rows", prefix(&[Is("grid"), Is("rows")])),
+
+    // https://tailwindcss.com/docs/grid-row
+    ("row-start-end", exact(&[Is("row"), Is("auto")])),
+    ("row-start-end", prefix(&[Is("row"), Is("span")])),
+    ("row-start", prefix(&[Is("row"), Is("start")])),
+    ("row-end", prefix(&[Is("row"), Is("end")])),
+
+    // https://tailwindcss.com/docs/grid-auto-flow
+    ("grid-auto-flow", exact(&[Is("grid"), Is("flow"), OneOf(&["row", "col", "dense"])])),
+    ("grid-auto-flow", exact(&[Is("grid"), Is("flow"), OneOf(&["row", "col"]), Is("dense")])),
+
+    // https://tailwindcss.com/docs/grid-auto-columns
+    ("auto-cols", prefix(&[Is("auto"), Is("cols")])),
+
+    // https://tailwindcss.com/docs/grid-auto-rows
+    ("auto-rows", prefix(&[Is("auto"), Is("rows")])),
+
+    // https://tailwindcss.com/docs/gap
+    ("gap-x", prefix(&[Is("gap"), Is("x")])),
+    ("gap-y", prefix(&[Is("gap"), Is("y")])),
+    ("gap", prefix(&[Is("gap")])),
+
+    // https://tailwindcss.com/docs/justify-content
+    // v4.1: safe alignment variants (justify-center-safe, etc.)
+    ("justify-content", exact(&[Is("justify"), OneOf(&["normal", "start", "end", "center", "between", "around", "evenly", "stretch"])])),
+    ("justify-content", exact(&[Is("justify"), OneOf(&["center", "start", "end"]), Is("safe")])),
+    // https://tailwindcss.com/docs/justify-items
+    ("justify-items", exact(&[Is("justify"), Is("items"), OneOf(&["start", "end", "center", "stretch"])])),
+    ("justify-items", exact(&[Is("justify"), Is("items"), OneOf(&["center", "start", "end"]), Is("safe")])),
+
+    // https://tailwindcss.com/docs/justify-self
+    ("justify-self", exact(&[Is("justify"), Is("self"), OneOf(&["start", "end", "center", "stretch"])])),
+    ("justify-self", exact(&[Is("justify"), Is("self"), OneOf(&["center", "start", "end"]), Is("safe")])),
+
+    // https://tailwindcss.com/docs/align-content
+    ("align-content", exact(&[Is("content"), OneOf(&["normal", "center", "start", "end", "between", "around", "evenly", "baseline", "stretch"])])),
+    ("align-content", exact(&[Is("content"), OneOf(&["center", "start", "end"]), Is("safe")])),
+
+    // https://tailwindcss.com/docs/align-items
+    // v4.1: items-baseline-last
+    ("align-items", exact(&[Is("items"), OneOf(&["start", "end", "center", "baseline", "stretch"])])),
+    ("align-items", exact(&[Is("items"), OneOf(&["center", "start", "end"]), Is("safe")])),
+    ("align-items", exact(&[Is("items"), Is("baseline"), Is("last")])),
+
+    // https://tailwindcss.com/docs/align-self
+    // v4.1: self-baseline-last
+    ("align-self", exact(&[Is("self"), OneOf(&["auto", "start", "end", "center", "stretch", "baseline"])])),
+    ("align-self", exact(&[Is("self"), OneOf(&["center", "start", "end"]), Is("safe")])),
+    ("align-self", exact(&[Is("self"), Is("baseline"), Is("last")])),
+
+    // https://tailwindcss.com/docs/place-content
+    ("place-content", exact(&[Is("place"), Is("content"), OneOf(&["center", "start", "end", "between", "around", "evenly", "baseline", "stretch"])])),
+    ("place-content", exact(&[Is("place"), Is("content"), OneOf(&["center", "start", "end"]), Is("safe")])),
+
+    // https://tailwindcss.com/docs/place-items
+    ("place-items", exact(&[Is("place"), Is("items"), OneOf(&["start", "end", "center", "baseline", "stretch"])])),
+    ("place-items", exact(&[Is("place"), Is("items"), OneOf(&["center", "start", "end"]), Is("safe")])),
+    // https://tailwindcss.com/docs/place-self
+    ("place-self", exact(&[Is("place"), Is("self"), OneOf(&["start", "end", "center", "baseline", "stretch"])])),
+    ("place-self", exact(&[Is("place"), Is("self"), OneOf(&["center", "start", "end"]), Is("safe")])),
+
+    // https://tailwindcss.com/docs/padding
+    ("padding", prefix(&[Is("p")])),
+    ("padding-left", prefix(&[Is("pl")])),
+    ("padding-right", prefix(&[Is("pr")])),
+    ("padding-top", prefix(&[Is("pt")])),
+    ("padding-bottom", prefix(&[Is("pb")])),
+    ("padding-x", prefix(&[Is("px")])),
+    ("padding-y", prefix(&[Is("py")])),
+
+    // https:: //tailwindcss.com/docs/margin
+    ("margin", prefix(&[Is("m")])),
+    ("margin-left", prefix(&[Is("ml")])),
+    ("margin-right", prefix(&[Is("mr")])),
+    ("margin-top", prefix(&[Is("mt")])),
+    ("margin-bottom", prefix(&[Is("mb")])),
+    ("margin-x", prefix(&[Is("mx")])),
+    ("margin-y", prefix(&[Is("my")])),
+    ("margin-start", prefix(&[Is("ms")])),
+    ("margin-end", prefix(&[Is("me")])),
+
+    // https://tailwindcss.com/docs/space
+    ("space-x-reverse", exact(&[Is("space"), Is("x"), Is("reverse")])),
+    ("space-y-reverse", exact(&[Is("space"), Is("y"), Is("reverse")])),
+    ("space-x", prefix(&[Is("space"), Is("x")])),
+    ("space-y", prefix(&[Is("space"), Is("y")])),
+
+    // https://tailwindcss.com/docs/width
+    // TODO: Add validation?
+    ("width", prefix(&[Is("w")])),
+
+    // https://tailwindcss.com/docs/min-width
+    // TODO: Add validation?
+    ("min-width", prefix(&[Is("min"), Is("w")])),
+
+    // https://tailwindcss.com/docs/max-width
+    ("max-width", prefix(&[Is("max"), Is("w")])),
+
+    // https://tailwindcss.com/docs/height
+    ("height", prefix(&[Is("h")])),
+
+    // https://tailwindcss.com/docs/min-height
+    ("min-height", prefix(&[Is("min"), Is("h")])),
+
+    // https://tailwindcss.com/docs/max-height
+    ("max-height", prefix(&[Is("max"), Is("h")])),
+
+    // https://tailwindcss.com/docs/size
+    ("size", prefix(&[Is("size")])),
+
+    // https://tailwindcss.com/docs/font-family
+    // TODO: This clash is bad
+    ("font-family", exact(&[Is("font"), OneOf(&["sans", "serif", "mono"])])),
+
+    // https://tailwindcss.com/docs/text-align
+    ("text-align", exact(&[Is("text"), OneOf(&["left", "center", "right", "justify", "start", "end"])])),
+
+    // https://tailwindcss.com/docs/text-overflow
+    ("text-overflow", exact(&[Is("text"), OneOf(&["ellipsis", "clip"])])),
+
+    // https://tailwindcss.com/docs/text-wrap
+    ("text-wrap", exact(&[Is("text"), OneOf(&["wrap", "nowrap", "balance", "pretty"])])),
+
+    // v4.1: https://tailwindcss.com/docs/overflow-wrap
+    ("overflow-wrap", exact(&[Is("wrap"), Is("break"), Is("word")])),
+    ("overflow-wrap", exact(&[Is("wrap"), OneOf(&["anywhere", "normal"])])),
+
+    // https://tailwindcss.com/docs/font-size
+    ("font-size", exact(&[Is("text"), Value(valid_text_size)])),
+    ("font-size", exact(&[Is("text")]).with_arbitrary(valid(is_arbitrary_len))),
+
+    // v4: https://tailwindcss.com/docs/text-shadow
+    ("text-shadow", exact(&[Is("text"), Is("shadow"), Is("none")])),
+    ("text-shadow", exact(&[Is("text"), Is("shadow"), Value(is_t_shirt_size)])),
+    ("text-shadow", exact(&[Is("text"), Is("shadow")])),
+    ("text-shadow-color", prefix(&[Is("text"), Is("shadow")])),
 
-        // https://tailwindcss.com/docs/text-transform
-        ["uppercase" | "lowercase" | "capitalize" | "normal-case"] => Ok("text-transform"),
+    // https://tailwindcss.com/docs/text-color
+    ("text-color", prefix(&[Is("text")])),
+
+    // https://tailwindcss.com/docs/font-smoothing
+    ("font-smoothing", exact(&[Is("antialiased")])),
+    ("font-smoothing", exact(&[Is("subpixel"), Is("antialiased")])),
 
-        // https://tailwindcss.com/docs/text-overflow
-        ["truncate"] => Ok("text-overflow"),
+    // https://tailwindcss.com/docs/font-style
+    ("font-style", exact(&[Is("italic")])),
+    ("font-style", exact(&[Is("not"), Is("italic")])),
 
-        // https://tailwindcss.com/docs/text-indent
-        ["indent", ..] => Ok("text-indent"),
+    // v4: https://tailwindcss.com/docs/font-stretch
+    ("font-stretch", prefix(&[Is("font"), Is("stretch")])),
 
-        // https://tailwindcss.com/docs/vertical-align
-        ["align", ..] => Ok("vertical-align"),
+    // https://tailwindcss.com/docs/font-weight
+    ("font-weight", prefix(&[Is("font")])),
 
-        // https://tailwindcss.com/docs/whitespace
-        ["whitespace", "normal"]
-        | ["whitespace", "nowrap"]
-        | ["whitespace", "pre"]
-        | ["whitespace", "pre", "line"]
-        | ["whitespace", "pre", "wrap"]
-        | ["whitespace", "break", "spaces"] => Ok("whitespace"),
+    // https://tailwindcss.com/docs/font-variant-numeric
+    ("fvn-normal", exact(&[Is("normal"), Is("nums")])),
+    ("fvn-ordinal", exact(&[Is("ordinal")])),
+    ("fvn-slashed-zero", exact(&[Is("slashed"), Is("zero")])),
+    ("fvn-figure", exact(&[OneOf(&["lining", "oldstyle"]), Is("nums")])),
+    ("fvn-spacing", exact(&[OneOf(&["proportional", "tabular"]), Is("nums")])),
+    ("fvn-fraction", exact(&[OneOf(&["diagonal", "stacked"]), Is("fractions")])),
 
-        // https://tailwindcss.com/docs/word-break
-        ["break", "normal" | "words" | "all" | "keep"] => {
-            Ok("word-break")
-        }
+    // https://tailwindcss.com/docs/letter-spacing
+    ("letter-spacing", prefix(&[Is("tracking")])),
 
-        // https://tailwindcss.com/docs/hyphens
-        ["hyphens", "none" | "manual" | "auto"] => Ok("hyphens"),
+    // https://tailwindcss.com/docs/line-clamp
+    ("line-clamp", prefix(&[Is("line"), Is("clamp")])),
 
-        // https://tailwindcss.com/docs/content
-        ["content", "none"] => Ok("content"),
-        ["content"] if is_arbitrary_value(arbitrary) => Ok("content"),
+    // https://tailwindcss.com/docs/line-height
+    ("line-height", prefix(&[Is("leading")])),
 
-        // https://tailwindcss.com/docs/background-attachment
-        ["bg", "fixed" | "local" | "scroll"] => Ok("background-attachment"),
+    // https://tailwindcss.com/docs/list-style-image
+    ("list-style-image", prefix(&[Is("list"), Is("image")])),
 
-        // https://tailwindcss.com/docs/background-clip
-        ["bg", "clip", "border" | "padding" | "content" | "text"] => Ok("background-clip"),
+    // https://tailwindcss.com/docs/list-style-position
+    ("list-style-position", exact(&[Is("list"), OneOf(&["inside", "outside"])])),
 
-        // https://tailwindcss.com/docs/background-origin
-        ["bg", "origin", "border" | "padding" | "content"] => {
-            Ok("background-origin")
-        }
+    // https://tailwindcss.com/docs/list-style-type
+    ("list-style-type", prefix(&[Is("list")])),
 
-        // https://tailwindcss.com/docs/background-repeat
-        ["bg", "repeat"]
-        | ["bg", "no","repeat"]
-        | ["bg", "repeat", "x" | "y" | "round" | "space"] => Ok("background-repeat"),
-
-        // https://tailwindcss.com/docs/background-position
-        // TODO: Integrate arbitrary value? (e.g. bg-[center_top_1rem])
-        ["bg", "bottom"]
-        | ["bg", "center"]
-        | ["bg", "left"]
-        | ["bg", "left", "bottom"]
-        | ["bg", "left", "top"]
-        | ["bg", "right"]
-        | ["bg", "right", "bottom"]
-        | ["bg", "right", "top"]
-        | ["bg", "top"] => Ok("background-position"),
-
-        // https://tailwindcss.com/docs/background-size
-        ["bg", "auto" | "cover" | "contain"] => Ok("background-size"),
-        ["bg"] if is_arbitrary_size(arbitrary) => Ok("background-size"),
-
-        // https://tailwindcss.com/docs/background-image
-        // v4: bg-linear-*, bg-conic-*, bg-radial-* (new gradient utilities)
-        ["bg", "none"]
-        | ["bg", "gradient", "to", ..]
-        | ["bg", "linear", ..]
-        | ["bg", "conic", ..]
-        | ["bg", "radial", ..] => Ok("background-image"),
-        ["bg"] if is_arbitrary_bg_image(arbitrary) => Ok("background-image"),
-
-        // https://tailwindcss.com/docs/background-blend-mode
-        // TODO: plus-lighter not valid
-        ["bg", "blend", mode @ ..] if valid_blend(mode) => Ok("background-blend-mode"),
-
-        // https://tailwindcss.com/docs/background-color
-        ["bg", ..] => Ok("background-color"),
-
-        // https://tailwindcss.com/docs/gradient-color-stops
-        // TODO: Review this?
-        ["from", ..] => Ok("from"),
-        ["via", ..] => Ok("via"),
-        ["to", ..] => Ok("to"),
-
-        // https://tailwindcss.com/docs/border-radius
-        // TODO: Review
-        ["rounded", "t", ..] => Ok("rounded-t"),
-        ["rounded", "r", ..] => Ok("rounded-r"),
-        ["rounded", "b", ..] => Ok("rounded-b"),
-        ["rounded", "l", ..] => Ok("rounded-l"),
-        ["rounded", "tl", ..] => Ok("rounded-tl"),
-        ["rounded", "tr", ..] => Ok("rounded-tr"),
-        ["rounded", "bl", ..] => Ok("rounded-bl"),
-        ["rounded", "br", ..] => Ok("rounded-br"),
-
-        ["rounded", "s", ..] => Ok("rounded-s"),
-        ["rounded", "e", ..] => Ok("rounded-e"),
-
-        ["rounded", "ss", ..] => Ok("rounded-ss"),
-        ["rounded", "se", ..] => Ok("rounded-se"),
-        ["rounded", "ee", ..] => Ok("rounded-ee"),
-        ["rounded", "es", ..] => Ok("rounded-es"),
-
-        ["rounded", ..] => Ok("rounded"),
-
-        // https://tailwindcss.com/docs/border-width
-        ["border", "x", rest] if is_valid_length(rest) => Ok("border-w-x"),
-        ["border", "x"] if arbitrary.is_empty() || is_arbitrary_len(arbitrary) => Ok("border-w-x"),
-        ["border", "y", rest] if is_valid_length(rest) => Ok("border-w-y"),
-        ["border", "y"] if arbitrary.is_empty() || is_arbitrary_len(arbitrary) => Ok("border-w-y"),
-        ["border", "t", rest] if is_valid_length(rest) => Ok("border-w-t"),
-        ["border", "t"] if arbitrary.is_empty() || is_arbitrary_len(arbitrary) => Ok("border-w-t"),
-        ["border", "r", rest] if arbitrary.is_empty() ||is_valid_length(rest) => Ok("border-w-r"),
-        ["border", "r"] if arbitrary.is_empty() || is_arbitrary_len(arbitrary) => Ok("border-w-r"),
-        ["border", "b", rest] if arbitrary.is_empty() ||is_valid_length(rest) => Ok("border-w-b"),
-        ["border", "b"] if arbitrary.is_empty() || is_arbitrary_len(arbitrary) => Ok("border-w-b"),
-        ["border", "l", rest] if is_valid_length(rest) => Ok("border-w-l"),
-        ["border", "l"] if arbitrary.is_empty() || is_arbitrary_len(arbitrary) => Ok("border-w-l"),
-        ["border", "s", rest] if is_valid_length(rest) => Ok("border-w-s"),
-        ["border", "s"] if arbitrary.is_empty() || is_arbitrary_len(arbitrary) => Ok("border-w-s"),
-        ["border", rest] if is_valid_length(rest) => Ok("border-w"),
-        ["border"] if arbitrary.is_empty() || is_arbitrary_len(arbitrary) => Ok("border-w"),
-
-        // https://tailwindcss.com/docs/border-style
-        ["border", "solid" | "dashed" | "dotted" | "double" | "hidden" | "none"] => {
-            Ok("border-style")
-        }
-        // https://tailwindcss.com/docs/border-collapse
-        ["border", "collapse" | "separate"] => Ok("border-collapse"),
-
-        // https://tailwindcss.com/docs/border-spacing
-        ["border", "spacing", "x", ..] => Ok("border-spacing-x"),
-        ["border", "spacing", "y", ..] => Ok("border-spacing-y"),
-        ["border", "spacing", ..] => Ok("border-spacing"),
-
-        // https://tailwindcss.com/docs/border-color
-        ["border", "t", ..] => Ok("border-color-t"),
-        ["border", "r", ..] => Ok("border-color-r"),
-        ["border", "b", ..] => Ok("border-color-b"),
-        ["border", "l", ..] => Ok("border-color-l"),
-        ["border", "s", ..] => Ok("border-color-s"),
-        ["border", "e", ..] => Ok("border-color-e"),
-        ["border", "x", ..] => Ok("border-color-x"),
-        ["border", "y", ..] => Ok("border-color-y"),
-        ["border", ..] => Ok("border-color"),
-
-        // https://tailwindcss.com/docs/divide-style
-        ["divide", "solid" | "dashed" | "dotted" | "double" | "none"] => Ok("divide-style"),
-
-        // https://tailwindcss.com/docs/divide-width
-        ["divide", "x", "reverse"] => Ok("divide-x-reverse"),
-        ["divide", "y", "reverse"] => Ok("divide-y-reverse"),
-        ["divide", "x", ..] => Ok("divide-x"),
-        ["divide", "y", ..] => Ok("divide-y"),
-
-        // https://tailwindcss.com/docs/divide-color
-        ["divide", ..] => Ok("divide-color"),
-
-        // https://tailwindcss.com/docs/outline-style
-        | ["outline"]
-        // v4: outline-hidden uses outline-style: none (different from outline-none which uses outline: 2px solid transparent)
-        | ["outline", "none" | "hidden" | "solid" | "dashed" | "dotted" | "double"]
-        // necessary for "outline"
-            if arbitrary.is_empty() =>
-        {
-            Ok("outline-style")
-        }
+    // https://tailwindcss.com/docs/text-decoration
+    ("text-decoration", exact(&[OneOf(&["underline", "overline"])])),
+    ("text-decoration", exact(&[Is("line"), Is("through")])),
+    ("text-decoration", exact(&[Is("no"), Is("underline")])),
 
-        // https://tailwindcss.com/docs/outline-width
-        ["outline"] if arbitrary.parse::<usize>().is_ok() => Ok("outline-width"),
-        ["outline", rest] if rest.parse::<usize>().is_ok() => Ok("outline-width"),
+    // https://tailwindcss.com/docs/text-decoration-style
+    ("text-decoration-style", exact(&[Is("decoration"), OneOf(&["solid", "double", "dotted", "dashed", "wavy"])])),
 
-        // https://tailwindcss.com/docs/outline-offset
-        ["outline", "offset", ..] => Ok("outline-offset"),
+    // https://tailwindcss.com/docs/text-decoration-thickness
+    ("text-decoration-thickness", exact(&[Is("decoration"), OneOf(&["auto", "from-font"])])),
+    ("text-decoration-thickness", exact(&[Is("decoration"), Value(is_usize)])),
+    ("text-decoration-thickness", exact(&[Is("decoration")]).with_arbitrary(valid(is_usize))),
 
-        // https://tailwindcss.com/docs/outline-color
-        ["outline", ..] => Ok("outline-color"),
+    // https://tailwindcss.com/docs/text-decoration-color
+    ("text-decoration-color", exact(&[Is("decoration")]).with_rest(Rest::Value(is_color_name)).with_arbitrary(NO_ARBITRARY)),
+    ("text-decoration-color", exact(&[Is("decoration")])),
 
-        // https://tailwindcss.com/docs/outline-offset
-        ["ring", "inset"] => Ok("ring-width"),
-        ["ring", rest] if rest.parse::<usize>().is_ok() => Ok("ring-width"),
-        ["ring"] if arbitrary.parse::<usize>().is_ok() => Ok("ring-width"),
+    // https://tailwindcss.com/docs/text-underline-offset
+    ("text-underline-offset", prefix(&[Is("underline"), Is("offset")])),
 
-        // https://tailwindcss.com/docs/ring-offset-width
-        ["ring", "offset", rest] if rest.parse::<usize>().is_ok() => Ok("ring-offset-width"),
-        ["ring", "offset"] if arbitrary.parse::<usize>().is_ok() => Ok("ring-offset-width"),
+    // https://tailwindcss.com/docs/text-transform
+    ("text-transform", exact(&[OneOf(&["uppercase", "lowercase", "capitalize", "normal-case"])])),
+
+    // https://tailwindcss.com/docs/text-overflow
+    ("text-overflow", exact(&[Is("truncate")])),
+
+    // https://tailwindcss.com/docs/text-indent
+    ("text-indent", prefix(&[Is("indent")])),
+
+    // https://tailwindcss.com/docs/vertical-align
+    ("vertical-align", prefix(&[Is("align")])),
+
+    // https://tailwindcss.com/docs/whitespace
+    ("whitespace", exact(&[Is("whitespace"), OneOf(&["normal", "nowrap", "pre"])])),
+    ("whitespace", exact(&[Is("whitespace"), Is("pre"), OneOf(&["line", "wrap"])])),
+    ("whitespace", exact(&[Is("whitespace"), Is("break"), Is("spaces")])),
+
+    // https://tailwindcss.com/docs/word-break
+    ("word-break", exact(&[Is("break"), OneOf(&["normal", "words", "all", "keep"])])),
+
+    // https://tailwindcss.com/docs/hyphens
+    ("hyphens", exact(&[Is("hyphens"), OneOf(&["none", "manual", "auto"])])),
+
+    // https://tailwindcss.com/docs/content
+    ("content", exact(&[Is("content"), Is("none")])),
+    ("content", exact(&[Is("content")]).with_arbitrary(valid(is_arbitrary_value))),
+
+    // https://tailwindcss.com/docs/background-attachment
+    ("background-attachment", exact(&[Is("bg"), OneOf(&["fixed", "local", "scroll"])])),
+
+    // https://tailwindcss.com/docs/background-clip
+    ("background-clip", exact(&[Is("bg"), Is("clip"), OneOf(&["border", "padding", "content", "text"])])),
+
+    // https://tailwindcss.com/docs/background-origin
+    ("background-origin", exact(&[Is("bg"), Is("origin"), OneOf(&["border", "padding", "content"])])),
+
+    // https://tailwindcss.com/docs/background-repeat
+    ("background-repeat", exact(&[Is("bg"), Is("repeat")])),
+    ("background-repeat", exact(&[Is("bg"), Is("no"), Is("repeat")])),
+    ("background-repeat", exact(&[Is("bg"), Is("repeat"), OneOf(&["x", "y", "round", "space"])])),
+
+    // https://tailwindcss.com/docs/background-position
+    // TODO: Integrate arbitrary value? (e.g. bg-[center_top_1rem])
+    ("background-position", exact(&[Is("bg"), OneOf(&["bottom", "center", "left", "right", "top"])])),
+    ("background-position", exact(&[Is("bg"), OneOf(&["left", "right"]), OneOf(&["bottom", "top"])])),
+
+    // https://tailwindcss.com/docs/background-size
+    ("background-size", exact(&[Is("bg"), OneOf(&["auto", "cover", "contain"])])),
+    ("background-size", exact(&[Is("bg")]).with_arbitrary(valid(is_arbitrary_size))),
+
+    // https://tailwindcss.com/docs/background-image
+    // v4: bg-linear-*, bg-conic-*, bg-radial-* (new gradient utilities)
+    ("background-image", exact(&[Is("bg"), Is("none")])),
+    ("background-image", prefix(&[Is("bg"), Is("gradient"), Is("to")])),
+    ("background-image", prefix(&[Is("bg"), OneOf(&["linear", "conic", "radial"])])),
+    ("background-image", exact(&[Is("bg")]).with_arbitrary(valid(is_arbitrary_bg_image))),
+
+    // https://tailwindcss.com/docs/background-blend-mode
+    // TODO: plus-lighter not valid
+    ("background-blend-mode", exact(&[Is("bg"), Is("blend")]).with_rest(Rest::Value(valid_blend))),
+
+    // https://tailwindcss.com/docs/background-color
+    ("background-color", prefix(&[Is("bg")])),
+
+    // https://tailwindcss.com/docs/gradient-color-stops
+    // TODO: Review this?
+    ("from", prefix(&[Is("from")])),
+    ("via", prefix(&[Is("via")])),
+    ("to", prefix(&[Is("to")])),
+
+    // https://tailwindcss.com/docs/border-radius
+    // TODO: Review
+    ("rounded-t", prefix(&[Is("rounded"), Is("t")])),
+    ("rounded-r", prefix(&[Is("rounded"), Is("r")])),
+    ("rounded-b", prefix(&[Is("rounded"), Is("b")])),
+    ("rounded-l", prefix(&[Is("rounded"), Is("l")])),
+    ("rounded-tl", prefix(&[Is("rounded"), Is("tl")])),
+    ("rounded-tr", prefix(&[Is("rounded"), Is("tr")])),
+    ("rounded-bl", prefix(&[Is("rounded"), Is("bl")])),
+    ("rounded-br", prefix(&[Is("rounded"), Is("br")])),
+
+    ("rounded-s", prefix(&[Is("rounded"), Is("s")])),
+    ("rounded-e", prefix(&[Is("rounded"), Is("e")])),
+
+    ("rounded-ss", prefix(&[Is("rounded"), Is("ss")])),
+    ("rounded-se", prefix(&[Is("rounded"), Is("se")])),
+    ("rounded-ee", prefix(&[Is("rounded"), Is("ee")])),
+    ("rounded-es", prefix(&[Is("rounded"), Is("es")])),
+
+    ("rounded", prefix(&[Is("rounded")])),
+
+    // https://tailwindcss.com/docs/border-width
+    ("border-w-x", exact(&[Is("border"), Is("x"), Value(is_valid_length)])),
+    ("border-w-x", exact(&[Is("border"), Is("x")]).with_arbitrary(valid(is_empty_or_arbitrary_len))),
+    ("border-w-y", exact(&[Is("border"), Is("y"), Value(is_valid_length)])),
+    ("border-w-y", exact(&[Is("border"), Is("y")]).with_arbitrary(valid(is_empty_or_arbitrary_len))),
+    ("border-w-t", exact(&[Is("border"), Is("t"), Value(is_valid_length)])),
+    ("border-w-t", exact(&[Is("border"), Is("t")]).with_arbitrary(valid(is_empty_or_arbitrary_len))),
+    ("border-w-r", exact(&[Is("border"), Is("r"), Value(is_valid_length)])),
+    ("border-w-r", exact(&[Is("border"), Is("r")]).with_arbitrary(valid(is_empty_or_arbitrary_len))),
+    ("border-w-b", exact(&[Is("border"), Is("b"), Value(is_valid_length)])),
+    ("border-w-b", exact(&[Is("border"), Is("b")]).with_arbitrary(valid(is_empty_or_arbitrary_len))),
+    ("border-w-l", exact(&[Is("border"), Is("l"), Value(is_valid_length)])),
+    ("border-w-l", exact(&[Is("border"), Is("l")]).with_arbitrary(valid(is_empty_or_arbitrary_len))),
+    ("border-w-s", exact(&[Is("border"), Is("s"), Value(is_valid_length)])),
+    ("border-w-s", exact(&[Is("border"), Is("s")]).with_arbitrary(valid(is_empty_or_arbitrary_len))),
+    ("border-w", exact(&[Is("border"), Value(is_valid_length)])),
+    ("border-w", exact(&[Is("border")]).with_arbitrary(valid(is_empty_or_arbitrary_len))),
+
+    // https://tailwindcss.com/docs/border-style
+    ("border-style", exact(&[Is("border"), OneOf(&["solid", "dashed", "dotted", "double", "hidden", "none"])])),
+
+    // https://tailwindcss.com/docs/border-collapse
+    ("border-collapse", exact(&[Is("border"), OneOf(&["collapse", "separate"])])),
+
+    // https://tailwindcss.com/docs/border-spacing
+    ("border-spacing-x", prefix(&[Is("border"), Is("spacing"), Is("x")])),
+    ("border-spacing-y", prefix(&[Is("border"), Is("spacing"), Is("y")])),
+    ("border-spacing", prefix(&[Is("border"), Is("spacing")])),
+
+    // https://tailwindcss.com/docs/border-color
+    ("border-color-t", prefix(&[Is("border"), Is("t")])),
+    ("border-color-r", prefix(&[Is("border"), Is("r")])),
+    ("border-color-b", prefix(&[Is("border"), Is("b")])),
+    ("border-color-l", prefix(&[Is("border"), Is("l")])),
+    ("border-color-s", prefix(&[Is("border"), Is("s")])),
+    ("border-color-e", prefix(&[Is("border"), Is("e")])),
+    ("border-color-x", prefix(&[Is("border"), Is("x")])),
+    ("border-color-y", prefix(&[Is("border"), Is("y")])),
+    ("border-color", prefix(&[Is("border")])),
+
+    // https://tailwindcss.com/docs/divide-style
+    ("divide-style", exact(&[Is("divide"), OneOf(&["solid", "dashed", "dotted", "double", "none"])])),
+
+    // https://tailwindcss.com/docs/divide-width
+    ("divide-x-reverse", exact(&[Is("divide"), Is("x"), Is("reverse")])),
+    ("divide-y-reverse", exact(&[Is("divide"), Is("y"), Is("reverse")])),
+    ("divide-x", prefix(&[Is("divide"), Is("x")])),
+    ("divide-y", prefix(&[Is("divide"), Is("y")])),
+
+    // https://tailwindcss.com/docs/divide-color
+    ("divide-color", prefix(&[Is("divide")])),
+
+    // https://tailwindcss.com/docs/outline-style
+    // v4: outline-hidden uses outline-style: none (different from outline-none which uses outline: 2px solid transparent)
+    // necessary for "outline"
+    ("outline-style", exact(&[Is("outline")]).with_arbitrary(NO_ARBITRARY)),
+    ("outline-style", exact(&[Is("outline"), OneOf(&["none", "hidden", "solid", "dashed", "dotted", "double"])]).with_arbitrary(NO_ARBITRARY)),
+
+    // https://tailwindcss.com/docs/outline-width
+    ("outline-width", exact(&[Is("outline")]).with_arbitrary(valid(is_usize))),
+    ("outline-width", exact(&[Is("outline"), Value(is_usize)])),
+
+    // https://tailwindcss.com/docs/outline-offset
+    ("outline-offset", prefix(&[Is("outline"), Is("offset")])),
+
+    // https://tailwindcss.com/docs/outline-color
+    ("outline-color", prefix(&[Is("outline")])),
+
+    // https://tailwindcss.com/docs/outline-offset
+    ("ring-width", exact(&[Is("ring"), Is("inset")])),
+    ("ring-width", exact(&[Is("ring"), Value(is_usize)])),
+    ("ring-width", exact(&[Is("ring")]).with_arbitrary(valid(is_usize))),
+
+    // https://tailwindcss.com/docs/ring-offset-width
+    ("ring-offset-width", exact(&[Is("ring"), Is("offset"), Value(is_usize)])),
+    ("ring-offset-width", exact(&[Is("ring"), Is("offset")]).with_arbitrary(valid(is_usize))),
+
+    // https://tailwindcss.com/docs/ring-offset-color
+    ("ring-offset-color", prefix(&[Is("ring"), Is("offset")])),
+    ("ring-color", prefix(&[Is("ring")])),
+
+    // https://tailwindcss.com/docs/box-shadow
+    // TODO: handle conflict with color + arbitrary
+    ("box-shadow", exact(&[Is("shadow")]).with_arbitrary(NO_ARBITRARY)),
+    ("box-shadow", exact(&[Is("shadow"), OneOf(&["inner", "none"])]).with_arbitrary(NO_ARBITRARY)),
+    ("box-shadow", exact(&[Is("shadow"), Value(is_t_shirt_size)])),
+
+    // https://tailwindcss.com/docs/box-shadow-color
+    ("box-shadow-color", prefix(&[Is("shadow")])),
 
-        // https://tailwindcss.com/docs/ring-offset-color
-        ["ring", "offset", ..] => Ok("ring-offset-color"),
-        ["ring", ..] => Ok("ring-color"),
+    // https://tailwindcss.com/docs/opacity
+    ("opacity", prefix(&[Is("opacity")])),
 
+    // https://tailwindcss.com/docs/mix-blend-mode
+    ("mix-blend-mode", exact(&[Is("mix"), Is("blend")]).with_rest(Rest::Value(valid_blend))),
 
-        // https://tailwindcss.com/docs/box-shadow
-        // TODO: handle conflict with color + arbitrary
-        ["shadow"] | ["shadow", "inner" | "none"] if arbitrary.is_empty() => Ok("box-shadow"),
-        ["shadow", size] if is_t_shirt_size(size) => Ok("box-shadow"),
+    // https://tailwindcss.com/docs/blur
+    ("blur", prefix(&[Is("blur")])),
 
-        // https://tailwindcss.com/docs/box-shadow-color
-        ["shadow", ..] => Ok("box-shadow-color"),
+    // https: //tailwindcss.com/docs/brightness
+    ("brightness", prefix(&[Is("brightness")])),
 
-        // https://tailwindcss.com/docs/opacity
-        ["opacity", ..] => Ok("opacity"),
+    // https://tailwindcss.com/docs/contrast
+    ("contrast", prefix(&[Is("contrast")])),
 
-        // https://tailwindcss.com/docs/mix-blend-mode
-        ["mix", "blend", mode @ ..] if valid_blend(mode)=> Ok("mix-blend-mode"),
+    // https://tailwindcss.com/docs/drop-shadow
+    // v4.1: drop-shadow-<color> support
+    ("drop-shadow", exact(&[Is("drop"), Is("shadow"), Is("none")])),
+    ("drop-shadow", exact(&[Is("drop"), Is("shadow"), Value(is_t_shirt_size)])),
+    ("drop-shadow", exact(&[Is("drop"), Is("shadow")])),
+    ("drop-shadow-color", prefix(&[Is("drop"), Is("shadow")])),
 
-        // https://tailwindcss.com/docs/blur
-        ["blur", ..] => Ok("blur"),
+    // https://tailwindcss.com/docs/grayscale
+    ("grayscale", prefix(&[Is("grayscale")])),
 
-        // https: //tailwindcss.com/docs/brightness
-        ["brightness", ..] => Ok("brightness"),
+    // https://tailwindcss.com/docs/hue-rotate
+    ("hue-rotate", prefix(&[Is("hue"), Is("rotate")])),
 
-        // https://tailwindcss.com/docs/contrast
-        ["contrast", ..] => Ok("contrast"),
+    // https://tailwindcss.com/docs/invert
+    ("invert", prefix(&[Is("invert")])),
 
-        // https://tailwindcss.com/docs/drop-shadow
-        // v4.1: drop-shadow-<color> support
-        ["drop", "shadow", "none"] => Ok("drop-shadow"),
-        ["drop", "shadow", size] if is_t_shirt_size(size) => Ok("drop-shadow"),
-        ["drop", "shadow"] => Ok("drop-shadow"),
-        ["drop", "shadow", ..] => Ok("drop-shadow-color"),
+    // https://tailwindcss.com/docs/saturate
+    ("saturate", prefix(&[Is("saturate")])),
+
+    // https://tailwindcss.com/docs/sepia
+    ("sepia", prefix(&[Is("sepia")])),
+
+    // https://tailwindcss.com/docs/backdrop-blur
+    ("backdrop-blur", prefix(&[Is("backdrop"), Is("blur")])),
+
+    // https://tailwindcss.com/docs/backdrop-brightness
+    ("backdrop-brightness", prefix(&[Is("backdrop"), Is("brightness")])),
+
+    // https://tailwindcss.com/docs/backdrop-contrast
+    ("backdrop-contrast", prefix(&[Is("backdrop"), Is("contrast")])),
+
+    // https://tailwindcss.com/docs/backdrop-grayscale
+    ("backdrop-grayscale", prefix(&[Is("backdrop"), Is("grayscale")])),
+
+    // https://tailwindcss.com/docs/backdrop-hue-rotate
+    ("backdrop-hue-rotate", prefix(&[Is("backdrop"), Is("hue"), Is("rotate")])),
+
+    // https://tailwindcss.com/docs/backdrop-invert
+    ("backdrop-invert", prefix(&[Is("backdrop"), Is("invert")])),
+
+    // https://tailwindcss.com/docs/backdrop-opacity
+    ("backdrop-opacity", prefix(&[Is("backdrop"), Is("opacity")])),
+
+    // https://tailwindcss.com/docs/backdrop-saturate
+    ("backdrop-saturate", prefix(&[Is("backdrop"), Is("saturate")])),
+
+    // https://tailwindcss.com/docs/backdrop-sepia
+    ("backdrop-sepia", prefix(&[Is("backdrop"), Is("sepia")])),
+
+    // https://tailwindcss.com/docs/table-layout
+    ("table-layout", exact(&[Is("table"), OneOf(&["auto", "fixed"])])),
+
+    // https://tailwindcss.com/docs/caption-side
+    ("caption-side", exact(&[Is("caption"), OneOf(&["top", "bottom"])])),
+
+    // v4: https://tailwindcss.com/docs/transition-behavior
+    ("transition-behavior", exact(&[Is("transition"), OneOf(&["normal", "discrete"])])),
+
+    // https://tailwindcss.com/docs/transition-property
+    ("transition-property", prefix(&[Is("transition")])),
+
+    // https://tailwindcss.com/docs/transition-duration
+    // Accepts: duration-150, duration-[240ms], duration-[.5s]
+    ("transition-duration", exact(&[Is("duration"), Value(is_usize)])),
+    ("transition-duration", exact(&[Is("duration")]).with_arbitrary(valid(is_arbitrary_time))),
+
+    // https://tailwindcss.com/docs/transition-timing-function
+    ("transition-timing-function", prefix(&[Is("ease")])),
+
+    // https://tailwindcss.com/docs/transition-delay
+    // Accepts: delay-150, delay-[240ms], delay-[.5s]
+    ("transition-delay", exact(&[Is("delay"), Value(is_usize)])),
+    ("transition-delay", exact(&[Is("delay")]).with_arbitrary(valid(is_arbitrary_time))),
+
+    // ============================================================================
+    // tailwindcss-animate / tw-animate-css plugin (Tailwind v4)
+    // https://github.com/romboHQ/tailwindcss-animate
+    // These classes provide entry/exit animations commonly used with shadcn/ui
+    // ============================================================================
 
-        // https://tailwindcss.com/docs/grayscale
-        ["grayscale", ..] => Ok("grayscale"),
+    // Animation state classes (required to trigger animations)
+    ("animate-in-out", exact(&[Is("animate"), OneOf(&["in", "out"])])),
 
-        // https://tailwindcss.com/docs/hue-rotate
-        ["hue", "rotate", ..] => Ok("hue-rotate"),
+    // https://tailwindcss.com/docs/animate (generic animate-* utilities)
+    ("animate", prefix(&[Is("animate")])),
 
-        // https://tailwindcss.com/docs/invert
-        ["invert", ..] => Ok("invert"),
+    // Fade animations (opacity): fade-in, fade-in-0 to fade-in-100, fade-out, fade-out-0 to fade-out-100
+    ("animate-opacity", prefix(&[Is("fade"), OneOf(&["in", "out"])])),
 
-        // https://tailwindcss.com/docs/saturate
-        ["saturate", ..] => Ok("saturate"),
+    // Zoom animations (scale): zoom-in, zoom-in-0 to zoom-in-200, zoom-out, zoom-out-0 to zoom-out-200
+    ("animate-scale", prefix(&[Is("zoom"), OneOf(&["in", "out"])])),
 
-        // https://tailwindcss.com/docs/sepia
-        ["sepia", ..] => Ok("sepia"),
+    // Spin animations (rotation): spin-in, spin-in-0 to spin-in-360, spin-out, spin-out-0 to spin-out-360
+    ("animate-rotate", prefix(&[Is("spin"), OneOf(&["in", "out"])])),
 
-        // https://tailwindcss.com/docs/backdrop-blur
-        ["backdrop", "blur", ..] => Ok("backdrop-blur"),
+    // Slide animations (translate)
+    // slide-in-from-top-*, slide-in-from-bottom-*, slide-in-from-left-*, slide-in-from-right-*
+    ("animate-translate-y", prefix(&[Is("slide"), Is("in"), Is("from"), OneOf(&["top", "bottom"])])),
+    ("animate-translate-x", prefix(&[Is("slide"), Is("in"), Is("from"), OneOf(&["left", "right"])])),
+    // slide-out-to-top-*, slide-out-to-bottom-*, slide-out-to-left-*, slide-out-to-right-*
+    ("animate-translate-y", prefix(&[Is("slide"), Is("out"), Is("to"), OneOf(&["top", "bottom"])])),
+    ("animate-translate-x", prefix(&[Is("slide"), Is("out"), Is("to"), OneOf(&["left", "right"])])),
 
-        // https://tailwindcss.com/docs/backdrop-brightness
-        ["backdrop", "brightness", ..] => Ok("backdrop-brightness"),
+    // https://tailwindcss.com/docs/scale
+    // v4: scale-none resets individual transform
+    ("scale", exact(&[Is("scale"), Is("none")])),
+    ("scale-x", exact(&[Is("scale"), Is("x"), Is("none")])),
+    ("scale-y", exact(&[Is("scale"), Is("y"), Is("none")])),
+    ("scale-z", exact(&[Is("scale"), Is("z"), Is("none")])),
+    ("scale-x", exact(&[Is("scale"), Is("x"), Value(is_usize)])),
+    ("scale-x", exact(&[Is("scale"), Is("x")]).with_arbitrary(valid(is_usize))),
+    ("scale-y", exact(&[Is("scale"), Is("y"), Value(is_usize)])),
+    ("scale-y", exact(&[Is("scale"), Is("y")]).with_arbitrary(valid(is_usize))),
+    // v4: scale-z for 3D transforms
+    ("scale-z", exact(&[Is("scale"), Is("z"), Value(is_usize)])),
+    ("scale-z", exact(&[Is("scale"), Is("z")]).with_arbitrary(valid(is_usize))),
+    ("scale", exact(&[Is("scale"), Value(is_usize)])),
+    // [1.75] is valid
+    ("scale", exact(&[Is("scale")]).with_arbitrary(valid(|arbitrary| arbitrary.parse::<f32>().is_ok()))),
+
+    // https://tailwindcss.com/docs/rotate
+    // v4: rotate-none resets individual transform
+    ("rotate", exact(&[Is("rotate"), Is("none")])),
+    ("rotate", exact(&[Is("rotate"), Value(is_usize)])),
+    ("rotate", exact(&[Is("rotate")]).with_arbitrary(valid(is_usize))),
+    // v4: 3D rotations
+    ("rotate-x", prefix(&[Is("rotate"), Is("x")])),
+    ("rotate-y", prefix(&[Is("rotate"), Is("y")])),
+    ("rotate-z", prefix(&[Is("rotate"), Is("z")])),
+
+    // https://tailwindcss.com/docs/translate
+    // v4: translate-none resets individual transform
+    ("translate", exact(&[Is("translate"), Is("none")])),
+    ("translate-x", prefix(&[Is("translate"), Is("x")])),
+    ("translate-y", prefix(&[Is("translate"), Is("y")])),
+    // v4: translate-z for 3D transforms
+    ("translate-z", prefix(&[Is("translate"), Is("z")])),
+
+    // https://tailwindcss.com/docs/skew
+    // v4: skew-none resets individual transform
+    ("skew", exact(&[Is("skew"), Is("none")])),
+    ("skew-x", prefix(&[Is("skew"), Is("x")])),
+    ("skew-y", prefix(&[Is("skew"), Is("y")])),
+
+    // https://tailwindcss.com/docs/transform-origin
+    ("transform-origin", prefix(&[Is("origin")])),
+
+    // v4: https://tailwindcss.com/docs/perspective
+    ("perspective-origin", prefix(&[Is("perspective"), Is("origin")])),
+    ("perspective", prefix(&[Is("perspective")])),
+
+    // v4: https://tailwindcss.com/docs/backface-visibility
+    ("backface-visibility", exact(&[Is("backface"), OneOf(&["visible", "hidden"])])),
+
+    // v4: transform-3d utility
+    ("transform-3d", exact(&[Is("transform"), Is("3d")])),
+
+    // https://tailwindcss.com/docs/transform (Tailwind v4)
+    // The `transform` class enables GPU acceleration for transforms.
+    ("transform", exact(&[Is("transform")])),
+    ("transform", exact(&[Is("transform"), OneOf(&["gpu", "none"])])),
+
+    // https://tailwindcss.com/docs/accent-color
+    ("accent-color", prefix(&[Is("accent")])),
+
+    // v4: https://tailwindcss.com/docs/color-scheme
+    ("color-scheme", exact(&[Is("scheme"), OneOf(&["light", "dark"])])),
+    ("color-scheme", exact(&[Is("scheme"), Is("light"), Is("dark")])),
+
+    // https://tailwindcss.com/docs/appearance
+    ("appearance", exact(&[Is("appearance"), OneOf(&["none", "auto"])])),
+
+    // https://tailwindcss.com/docs/cursor
+    ("cursor", prefix(&[Is("cursor")])),
+
+    // https://tailwindcss.com/docs/caret-color
+    ("caret-color", prefix(&[Is("caret")])),
+
+    // https://tailwindcss.com/docs/pointer-events
+    ("pointer-events", exact(&[Is("pointer"), Is("events"), OneOf(&["none", "auto"])])),
+
+    // https://tailwindcss.com/docs/resize
+    ("resize", exact(&[Is("resize")])),
+    ("resize", exact(&[Is("resize"), OneOf(&["none", "y", "x"])])),
+
+    // https://tailwindcss.com/docs/scroll-behavior
+    ("scroll-behavior", exact(&[Is("scroll"), OneOf(&["auto", "smooth"])])),
+
+    // https://tailwindcss.com/docs/scroll-margin
+    ("scroll-margin", prefix(&[Is("scroll"), Value(|rest| rest.starts_with('m'))])),
+
+    // https://tailwindcss.com/docs/scroll-padding
+    ("scroll-padding", prefix(&[Is("scroll"), Value(|rest| rest.starts_with('p'))])),
+
+    // https://tailwindcss.com/docs/scroll-snap-align
+    // snap-start, snap-end, snap-center (2 elements)
+    ("scroll-snap-align", exact(&[Is("snap"), OneOf(&["start", "end", "center"])])),
+    // snap-align-none (3 elements)
+    ("scroll-snap-align", exact(&[Is("snap"), Is("align"), Is("none")])),
+
+    // https://tailwindcss.com/docs/scroll-snap-stop#forcing-snap-position-stops
+    ("scroll-snap-stop", exact(&[Is("snap"), OneOf(&["normal", "always"])])),
+
+    // https://tailwindcss.com/docs/scroll-snap-type
+    ("scroll-snap-type", exact(&[Is("snap"), OneOf(&["none", "x", "y", "both", "mandatory", "proximity"])])),
+
+    // https://tailwindcss.com/docs/touch-action
+    ("touch", exact(&[Is("touch"), OneOf(&["auto", "none", "manipulation"])])),
+    ("touch-x", exact(&[Is("touch"), Is("pan"), OneOf(&["x", "left", "right"])])),
+    ("touch-y", exact(&[Is("touch"), Is("pan"), OneOf(&["y", "up", "down"])])),
+    ("touch-pz", exact(&[Is("touch"), Is("pinch"), Is("zoom")])),
+
+    // https://tailwindcss.com/docs/user-select
+    ("user-select", exact(&[Is("select"), OneOf(&["none", "text", "all", "auto"])])),
+
+    // https://tailwindcss.com/docs/will-change
+    ("will-change", prefix(&[Is("will"), Is("change")])),
+
+    // https://tailwindcss.com/docs/fill
+    ("fill", prefix(&[Is("fill")])),
+
+    // https://tailwindcss.com/docs/stroke-width
+    ("stroke-width", exact(&[Is("stroke"), Value(is_usize)])),
+    ("stroke-width", exact(&[Is("stroke")]).with_arbitrary(valid(is_valid_length))),
+
+    // https://tailwindcss.com/docs/stroke
+    ("stroke", prefix(&[Is("stroke")])),
+
+    // https://tailwindcss.com/docs/screen-readers
+    ("screen-readers", exact(&[Is("sr"), Is("only")])),
+    ("screen-readers", exact(&[Is("not"), Is("sr"), Is("only")])),
+
+    // Typography plugin: https://github.com/tailwindlabs/tailwindcss-typography
+    ("not-prose", exact(&[Is("not"), Is("prose")])),
+    ("prose", prefix(&[Is("prose")])),
+
+    // https://tailwindcss.com/docs/forced-color-adjust
+    ("forced-color-adjust", exact(&[Is("forced"), Is("color"), Is("adjust"), OneOf(&["auto", "none"])])),
+
+    // v4: https://tailwindcss.com/docs/field-sizing
+    ("field-sizing", exact(&[Is("field"), Is("sizing"), OneOf(&["content", "fixed"])])),
+
+    // v4: https://tailwindcss.com/docs/mask-image
+    ("mask-image", exact(&[Is("mask"), Is("none")])),
+    ("mask-type", prefix(&[Is("mask"), Is("type")])),
+    ("mask-position", prefix(&[Is("mask"), Is("position")])),
+    ("mask-size", prefix(&[Is("mask"), Is("size")])),
+    ("mask-repeat", prefix(&[Is("mask"), Is("repeat")])),
+    ("mask-origin", prefix(&[Is("mask"), Is("origin")])),
+    ("mask-clip", prefix(&[Is("mask"), Is("clip")])),
+    ("mask-composite", prefix(&[Is("mask"), Is("composite")])),
+    // v4.1: directional gradient masks
+    ("mask-linear-t", prefix(&[Is("mask"), Is("t")])),
+    ("mask-linear-r", prefix(&[Is("mask"), Is("r")])),
+    ("mask-linear-b", prefix(&[Is("mask"), Is("b")])),
+    ("mask-linear-l", prefix(&[Is("mask"), Is("l")])),
+    // v4.1: radial/conic gradient masks
+    ("mask-radial", prefix(&[Is("mask"), Is("radial")])),
+    ("mask-conic", prefix(&[Is("mask"), Is("conic")])),
+    ("mask-image", prefix(&[Is("mask")])),
+
+    // https://tailwindcss.com/docs/hover-focus-and-other-states#styling-based-on-parent-state
+    // named group: group/item (parser splits on - so group/input-group becomes ["group/input", "group"])
+    ("group", prefix(&[Is("group")])),
+    ("group", prefix(&[Value(|first| first.starts_with("group/"))])),
+
+    // https://tailwindcss.com/docs/hover-focus-and-other-states#styling-based-on-sibling-state
+    ("peer", prefix(&[Is("peer")])),
+    ("peer", prefix(&[Value(|first| first.starts_with("peer/"))])),
+
+    // BEM-style classes (e.g., toast__container, block__element--modifier)
+    // These are custom classes, not Tailwind utilities - pass them through
+    ("custom-bem", prefix(&[Value(|first| first.contains("__"))])),
+];
+
+/// CSS properties set by arbitrary properties like `[color:red]`, and the class group setting the same property.
+#[rustfmt::skip]
+pub(crate) static PROPERTIES: &[(&str, &str)] = &[
+    ("display", "display"), ("position", "position"), ("visibility", "visibility"), ("isolation", "isolation"),
+    ("float", "float"), ("clear", "clear"), ("box-sizing", "box-sizing"), ("z-index", "z-index"), ("order", "order"),
+    ("inset", "inset"), ("inset-inline", "inset-x"), ("inset-block", "inset-y"),
+    ("inset-inline-start", "start"), ("inset-inline-end", "end"),
+    ("top", "top"), ("right", "right"), ("bottom", "bottom"), ("left", "left"),
+    ("overflow", "overflow"), ("overscroll-behavior", "overscroll-behavior"), ("scroll-behavior", "scroll-behavior"),
+    ("margin", "margin"), ("margin-inline", "margin-x"), ("margin-block", "margin-y"),
+    ("margin-inline-start", "margin-start"), ("margin-inline-end", "margin-end"),
+    ("margin-top", "margin-top"), ("margin-right", "margin-right"),
+    ("margin-bottom", "margin-bottom"), ("margin-left", "margin-left"),
+    ("padding", "padding"), ("padding-inline", "padding-x"), ("padding-block", "padding-y"),
+    ("padding-top", "padding-top"), ("padding-right", "padding-right"),
+    ("padding-bottom", "padding-bottom"), ("padding-left", "padding-left"),
+    ("width", "width"), ("min-width", "min-width"), ("max-width", "max-width"),
+    ("height", "height"), ("min-height", "min-height"), ("max-height", "max-height"),
+    ("aspect-ratio", "aspect"), ("columns", "columns"),
+    ("flex", "flex"), ("flex-basis", "flex-basis"), ("flex-grow", "flex-grow"), ("flex-shrink", "flex-shrink"),
+    ("flex-direction", "flex-direction"), ("flex-wrap", "flex-wrap"),
+    ("grid-template-columns", "grid-template-columns"), ("grid-template-rows", "grid-template-rows"),
+    ("grid-auto-flow", "grid-auto-flow"), ("grid-auto-columns", "auto-cols"), ("grid-auto-rows", "auto-rows"),
+    ("gap", "gap"), ("column-gap", "gap-x"), ("row-gap", "gap-y"),
+    ("justify-content", "justify-content"), ("justify-items", "justify-items"), ("justify-self", "justify-self"),
+    ("align-content", "align-content"), ("align-items", "align-items"), ("align-self", "align-self"),
+    ("place-content", "place-content"), ("place-items", "place-items"), ("place-self", "place-self"),
+    ("font-family", "font-family"), ("font-size", "font-size"), ("font-weight", "font-weight"),
+    ("font-style", "font-style"), ("font-stretch", "font-stretch"), ("line-height", "line-height"),
+    ("letter-spacing", "letter-spacing"), ("color", "text-color"), ("text-align", "text-align"),
+    ("text-indent", "text-indent"), ("text-transform", "text-transform"), ("text-overflow", "text-overflow"),
+    ("text-wrap", "text-wrap"), ("text-shadow", "text-shadow"), ("vertical-align", "vertical-align"),
+    ("white-space", "whitespace"), ("word-break", "word-break"), ("overflow-wrap", "overflow-wrap"),
+    ("hyphens", "hyphens"), ("text-decoration-line", "text-decoration"),
+    ("text-decoration-color", "text-decoration-color"), ("text-decoration-style", "text-decoration-style"),
+    ("text-decoration-thickness", "text-decoration-thickness"), ("text-underline-offset", "text-underline-offset"),
+    ("list-style-type", "list-style-type"), ("list-style-position", "list-style-position"),
+    ("list-style-image", "list-style-image"),
+    ("background-color", "background-color"), ("background-image", "background-image"),
+    ("background-size", "background-size"), ("background-position", "background-position"),
+    ("background-repeat", "background-repeat"), ("background-attachment", "background-attachment"),
+    ("background-clip", "background-clip"), ("background-origin", "background-origin"),
+    ("background-blend-mode", "background-blend-mode"), ("mix-blend-mode", "mix-blend-mode"),
+    ("border-radius", "rounded"), ("border-width", "border-w"), ("border-style", "border-style"),
+    ("border-color", "border-color"), ("border-collapse", "border-collapse"), ("border-spacing", "border-spacing"),
+    ("outline-style", "outline-style"), ("outline-width", "outline-width"),
+    ("outline-offset", "outline-offset"), ("outline-color", "outline-color"),
+    ("box-shadow", "box-shadow"), ("opacity", "opacity"), ("box-decoration-break", "box-decoration-break"),
+    ("mask-image", "mask-image"), ("mask-type", "mask-type"), ("mask-size", "mask-size"),
+    ("mask-clip", "mask-clip"), ("mask-position", "mask-position"), ("mask-repeat", "mask-repeat"),
+    ("mask-origin", "mask-origin"), ("mask-composite", "mask-composite"),
+    ("object-fit", "object-fit"), ("object-position", "object-position"),
+    ("fill", "fill"), ("stroke", "stroke"), ("stroke-width", "stroke-width"),
+    ("table-layout", "table-layout"), ("caption-side", "caption-side"),
+    ("break-before", "break-before"), ("break-inside", "break-inside"), ("break-after", "break-after"),
+    ("transform", "transform"), ("transform-origin", "transform-origin"), ("transform-style", "transform-3d"),
+    ("translate", "translate"), ("rotate", "rotate"), ("scale", "scale"),
+    ("backface-visibility", "backface-visibility"), ("perspective", "perspective"),
+    ("perspective-origin", "perspective-origin"),
+    ("transition-property", "transition-property"), ("transition-behavior", "transition-behavior"),
+    ("transition-duration", "transition-duration"), ("transition-delay", "transition-delay"),
+    ("transition-timing-function", "transition-timing-function"), ("animation", "animate"),
+    ("will-change", "will-change"), ("content", "content"), ("cursor", "cursor"),
+    ("pointer-events", "pointer-events"), ("user-select", "user-select"), ("resize", "resize"),
+    ("touch-action", "touch"), ("appearance", "appearance"), ("accent-color", "accent-color"),
+    ("caret-color", "caret-color"), ("color-scheme", "color-scheme"), ("field-sizing", "field-sizing"),
+    ("forced-color-adjust", "forced-color-adjust"),
+    ("scroll-margin", "scroll-margin"), ("scroll-padding", "scroll-padding"),
+    ("scroll-snap-type", "scroll-snap-type"), ("scroll-snap-align", "scroll-snap-align"),
+    ("scroll-snap-stop", "scroll-snap-stop"),
+];
+
+/// The class groups accepting negative values, like `-mt-2` or `-translate-x-1/2`.
+#[rustfmt::skip]
+pub(crate) static NEGATIVE_GROUPS: &[&str] = &[
+    "inset", "inset-x", "inset-y", "start", "end", "top", "right", "bottom", "left",
+    "z-index", "order", "col-start", "col-end", "row-start", "row-end",
+    "margin", "margin-x", "margin-y", "margin-start", "margin-end",
+    "margin-top", "margin-right", "margin-bottom", "margin-left",
+    "space-x", "space-y", "scroll-margin",
+    "translate", "translate-x", "translate-y", "translate-z",
+    "rotate", "rotate-x", "rotate-y", "rotate-z", "skew", "skew-x", "skew-y",
+    "scale", "scale-x", "scale-y", "scale-z",
+    "letter-spacing", "text-indent", "text-underline-offset", "outline-offset",
+    "hue-rotate", "backdrop-hue-rotate",
+];
+
+/// Classes accepting negative values in groups that don't, the angles of gradients and masks.
+#[rustfmt::skip]
+pub(crate) static NEGATIVE_PATTERNS: &[(&str, ClassPattern)] = &[
+    // -bg-linear-45, -bg-conic-45
+    ("background-image", exact(&[Is("bg"), OneOf(&["linear", "conic"]), Value(is_usize)])),
+    // -mask-linear-45, -mask-conic-45
+    ("mask-image", exact(&[Is("mask"), Is("linear"), Value(is_usize)])),
+    ("mask-conic", exact(&[Is("mask"), Is("conic"), Value(is_usize)])),
+];
+
+/// The class groups accepting a postfix modifier: an opacity for colors (`bg-red-500/50`) and shadows (`shadow-lg/20`),
+/// a line-height for font sizes (`text-lg/7`), an interpolation for gradients (`bg-linear-to-r/oklch`)
+/// and a name for `group/card` and `peer/card`.
+#[rustfmt::skip]
+pub(crate) static MODIFIER_GROUPS: &[&str] = &[
+    "text-color", "text-shadow-color", "text-decoration-color", "background-color",
+    "border-color", "border-color-x", "border-color-y", "border-color-s", "border-color-e",
+    "border-color-t", "border-color-r", "border-color-b", "border-color-l",
+    "divide-color", "outline-color", "ring-color", "ring-offset-color", "inset-ring-color",
+    "box-shadow-color", "inset-shadow-color", "drop-shadow-color",
+    "accent-color", "caret-color", "fill", "stroke", "from", "via", "to",
+    "font-size", "box-shadow", "inset-shadow", "drop-shadow", "text-shadow", "background-image",
+    "group", "peer",
+];
+
+/// The class groups accepting a fraction as their value, like `w-1/2` or `aspect-16/9`.
+#[rustfmt::skip]
+pub(crate) static FRACTION_GROUPS: &[&str] = &[
+    "aspect", "flex-basis", "width", "min-width", "max-width", "height", "min-height", "max-height", "size",
+    "inset", "inset-x", "inset-y", "start", "end", "top", "right", "bottom", "left",
+    "translate", "translate-x", "translate-y",
+];
+
+/// Class groups whose last pattern accepts any value as a color, checked by [`is_color_value`] in strict mode.
+#[rustfmt::skip]
+pub(crate) static COLOR_GROUPS: &[&str] = &[
+    "text-color", "text-shadow-color", "text-decoration-color", "background-color",
+    "border-color", "border-color-x", "border-color-y", "border-color-s", "border-color-e",
+    "border-color-t", "border-color-r", "border-color-b", "border-color-l",
+    "divide-color", "outline-color", "ring-color", "ring-offset-color", "inset-ring-color",
+    "box-shadow-color", "inset-shadow-color", "drop-shadow-color",
+    "accent-color", "caret-color", "fill", "stroke",
+];
+
+/// Returns `true` if the value of a class in one of the [`COLOR_GROUPS`] is a color, `red-500` but not `rde-500`.
+pub(crate) fn is_color_value(id: &str, value: &[&str]) -> bool {
+    match value {
+        ["none"] => matches!(id, "fill" | "stroke"),
+        ["auto"] => id == "accent-color",
+        value => is_color_name(value),
+    }
+}
 
-        // https://tailwindcss.com/docs/backdrop-contrast
-        ["backdrop", "contrast", ..] => Ok("backdrop-contrast"),
+fn is_usize(input: &str) -> bool {
+    input.parse::<usize>().is_ok()
+}
 
-        // https://tailwindcss.com/docs/backdrop-grayscale
-        ["backdrop", "grayscale", ..] => Ok("backdrop-grayscale"),
+fn is_isize(input: &str) -> bool {
+    input.parse::<isize>().is_ok()
+}
 
-        // https://tailwindcss.com/docs/backdrop-hue-rotate
-        ["backdrop", "hue", "rotate", ..] => Ok("backdrop-hue-rotate"),
-
-        // https://tailwindcss.com/docs/backdrop-invert
-        ["backdrop", "invert", ..] => Ok("backdrop-invert"),
-
-        // https://tailwindcss.com/docs/backdrop-opacity
-        ["backdrop", "opacity", ..] => Ok("backdrop-opacity"),
-
-        // https://tailwindcss.com/docs/backdrop-saturate
-        ["backdrop", "saturate", ..] => Ok("backdrop-saturate"),
-
-        // https://tailwindcss.com/docs/backdrop-sepia
-        ["backdrop", "sepia", ..] => Ok("backdrop-sepia"),
-
-        // https://tailwindcss.com/docs/table-layout
-        ["table", "auto" | "fixed"] => Ok("table-layout"),
-
-        // https://tailwindcss.com/docs/caption-side
-        ["caption", "top" | "bottom"] => Ok("caption-side"),
-
-        // v4: https://tailwindcss.com/docs/transition-behavior
-        ["transition", "normal" | "discrete"] => Ok("transition-behavior"),
-
-        // https://tailwindcss.com/docs/transition-property
-        ["transition", ..] => Ok("transition-property"),
-
-        // https://tailwindcss.com/docs/transition-duration
-        // Accepts: duration-150, duration-[240ms], duration-[.5s]
-        ["duration", rest] if rest.parse::<usize>().is_ok() => Ok("transition-duration"),
-        ["duration"] if is_arbitrary_time(arbitrary) => Ok("transition-duration"),
-
-        // https://tailwindcss.com/docs/transition-timing-function
-        ["ease", ..] => Ok("transition-timing-function"),
-
-        // https://tailwindcss.com/docs/transition-delay
-        // Accepts: delay-150, delay-[240ms], delay-[.5s]
-        ["delay", rest] if rest.parse::<usize>().is_ok() => Ok("transition-delay"),
-        ["delay"] if is_arbitrary_time(arbitrary) => Ok("transition-delay"),
-
-        // ============================================================================
-        // tailwindcss-animate / tw-animate-css plugin (Tailwind v4)
-        // https://github.com/romboHQ/tailwindcss-animate
-        // These classes provide entry/exit animations commonly used with shadcn/ui
-        // ============================================================================
-
-        // Animation state classes (required to trigger animations)
-        ["animate", "in"] => Ok("animate-in-out"),
-        ["animate", "out"] => Ok("animate-in-out"),
-
-        // https://tailwindcss.com/docs/animate (generic animate-* utilities)
-        ["animate", ..] => Ok("animate"),
-
-        // Fade animations (opacity): fade-in, fade-in-0 to fade-in-100, fade-out, fade-out-0 to fade-out-100
-        ["fade", "in", ..] => Ok("animate-opacity"),
-        ["fade", "out", ..] => Ok("animate-opacity"),
-
-        // Zoom animations (scale): zoom-in, zoom-in-0 to zoom-in-200, zoom-out, zoom-out-0 to zoom-out-200
-        ["zoom", "in", ..] => Ok("animate-scale"),
-        ["zoom", "out", ..] => Ok("animate-scale"),
-
-        // Spin animations (rotation): spin-in, spin-in-0 to spin-in-360, spin-out, spin-out-0 to spin-out-360
-        ["spin", "in", ..] => Ok("animate-rotate"),
-        ["spin", "out", ..] => Ok("animate-rotate"),
-
-        // Slide animations (translate)
-        // slide-in-from-top-*, slide-in-from-bottom-*, slide-in-from-left-*, slide-in-from-right-*
-        ["slide", "in", "from", "top", ..] => Ok("animate-translate-y"),
-        ["slide", "in", "from", "bottom", ..] => Ok("animate-translate-y"),
-        ["slide", "in", "from", "left", ..] => Ok("animate-translate-x"),
-        ["slide", "in", "from", "right", ..] => Ok("animate-translate-x"),
-        // slide-out-to-top-*, slide-out-to-bottom-*, slide-out-to-left-*, slide-out-to-right-*
-        ["slide", "out", "to", "top", ..] => Ok("animate-translate-y"),
-        ["slide", "out", "to", "bottom", ..] => Ok("animate-translate-y"),
-        ["slide", "out", "to", "left", ..] => Ok("animate-translate-x"),
-        ["slide", "out", "to", "right", ..] => Ok("animate-translate-x"),
-
-        // https://tailwindcss.com/docs/scale
-        // v4: scale-none resets individual transform
-        ["scale", "none"] => Ok("scale"),
-        ["scale", "x", "none"] => Ok("scale-x"),
-        ["scale", "y", "none"] => Ok("scale-y"),
-        ["scale", "z", "none"] => Ok("scale-z"),
-        ["scale", "x", rest] if rest.parse::<usize>().is_ok() => Ok("scale-x"),
-        ["scale", "x"] if arbitrary.parse::<usize>().is_ok() => Ok("scale-x"),
-        ["scale", "y", rest] if rest.parse::<usize>().is_ok() => Ok("scale-y"),
-        ["scale", "y"] if arbitrary.parse::<usize>().is_ok() => Ok("scale-y"),
-        // v4: scale-z for 3D transforms
-        ["scale", "z", rest] if rest.parse::<usize>().is_ok() => Ok("scale-z"),
-        ["scale", "z"] if arbitrary.parse::<usize>().is_ok() => Ok("scale-z"),
-        ["scale", rest] if rest.parse::<usize>().is_ok() => Ok("scale"),
-        // [1.75] is valid
-        ["scale"] if arbitrary.parse::<f32>().is_ok() => Ok("scale"),
-
-        // https://tailwindcss.com/docs/rotate
-        // v4: rotate-none resets individual transform
-        ["rotate", "none"] => Ok("rotate"),
-        ["rotate", rest] if rest.parse::<usize>().is_ok() => Ok("rotate"),
-        ["rotate"] if arbitrary.parse::<usize>().is_ok() => Ok("rotate"),
-        // v4: 3D rotations
-        ["rotate", "x", "none"] => Ok("rotate-x"),
-        ["rotate", "y", "none"] => Ok("rotate-y"),
-        ["rotate", "z", "none"] => Ok("rotate-z"),
-        ["rotate", "x", ..] => Ok("rotate-x"),
-        ["rotate", "y", ..] => Ok("rotate-y"),
-        ["rotate", "z", ..] => Ok("rotate-z"),
-
-        // https://tailwindcss.com/docs/translate
-        // v4: translate-none resets individual transform
-        ["translate", "none"] => Ok("translate"),
-        ["translate", "x", "none"] => Ok("translate-x"),
-        ["translate", "y", "none"] => Ok("translate-y"),
-        ["translate", "z", "none"] => Ok("translate-z"),
-        ["translate", "x", ..]  => Ok("translate-x"),
-        ["translate", "y", ..]  => Ok("translate-y"),
-        // v4: translate-z for 3D transforms
-        ["translate", "z", ..] => Ok("translate-z"),
-
-        // https://tailwindcss.com/docs/skew
-        // v4: skew-none resets individual transform
-        ["skew", "none"] => Ok("skew"),
-        ["skew", "x", "none"] => Ok("skew-x"),
-        ["skew", "y", "none"] => Ok("skew-y"),
-        ["skew", "x", ..]  => Ok("skew-x"),
-        ["skew", "y", ..]  => Ok("skew-y"),
-
-        // https://tailwindcss.com/docs/transform-origin
-        ["origin", ..] => Ok("transform-origin"),
-
-        // v4: https://tailwindcss.com/docs/perspective
-        ["perspective", "origin", ..] => Ok("perspective-origin"),
-        ["perspective", "none"] => Ok("perspective"),
-        ["perspective", ..] => Ok("perspective"),
-
-        // v4: https://tailwindcss.com/docs/backface-visibility
-        ["backface", "visible" | "hidden"] => Ok("backface-visibility"),
-
-        // v4: transform-3d utility
-        ["transform", "3d"] => Ok("transform-3d"),
-
-        // https://tailwindcss.com/docs/transform (Tailwind v4)
-        // The `transform` class enables GPU acceleration for transforms.
-        ["transform"] | ["transform", "gpu"] | ["transform", "none"] => Ok("transform"),
-
-        // https://tailwindcss.com/docs/accent-color
-        ["accent", ..] => Ok("accent-color"),
-
-        // v4: https://tailwindcss.com/docs/color-scheme
-        ["scheme", "light"] | ["scheme", "dark"] | ["scheme", "light", "dark"] => Ok("color-scheme"),
-
-        // https://tailwindcss.com/docs/appearance
-        ["appearance", "none" | "auto"] => Ok("appearance"),
-
-        // https://tailwindcss.com/docs/cursor
-        ["cursor", ..] => Ok("cursor"),
-
-        // https://tailwindcss.com/docs/caret-color
-        ["caret", ..] => Ok("caret-color"),
-
-        // https://tailwindcss.com/docs/pointer-events
-        ["pointer", "events",  "none" | "auto"] => Ok("pointer-events"),
-
-        // https://tailwindcss.com/docs/resize
-        ["resize"] | ["resize", "none" | "y" |"x"]   => Ok("resize"),
-
-        // https://tailwindcss.com/docs/scroll-behavior
-        ["scroll", "auto" | "smooth"] => Ok("scroll-behavior"),
-
-        // https://tailwindcss.com/docs/scroll-margin
-        ["scroll", rest, ..] if rest.starts_with('m') => Ok("scroll-margin"),
-
-        // https://tailwindcss.com/docs/scroll-padding
-        ["scroll", rest, ..] if rest.starts_with('p') => Ok("scroll-padding"),
-
-        // https://tailwindcss.com/docs/scroll-snap-align
-        // snap-start, snap-end, snap-center (2 elements)
-        ["snap", "start" | "end" | "center"] => Ok("scroll-snap-align"),
-        // snap-align-none (3 elements)
-        ["snap", "align", "none"] => Ok("scroll-snap-align"),
-
-        // https://tailwindcss.com/docs/scroll-snap-stop#forcing-snap-position-stops
-        ["snap", "normal"] | ["snap", "always"] => Ok("scroll-snap-stop"),
-
-        // https://tailwindcss.com/docs/scroll-snap-type
-        ["snap", "none" | "x" | "y" | "both" | "mandatory" | "proximity"] => {
-            Ok("scroll-snap-type")
-        }
+fn is_fraction(input: &str) -> bool {
+    parse_fraction(input).is_some()
+}
 
-        // https://tailwindcss.com/docs/touch-action
-        ["touch", "auto" | "none" | "manipulation"] => Ok("touch"),
-        ["touch", "pan", "x" | "left" | "right"] => Ok("touch-x"),
-        ["touch", "pan", "y"| "up"| "down"] => Ok("touch-y"),
-        ["touch", "pinch", "zoom"] => Ok("touch-pz"),
-
-        // https://tailwindcss.com/docs/user-select
-        ["select" , "none" | "text" | "all" | "auto"] => Ok("user-select"),
-
-        // https://tailwindcss.com/docs/will-change
-        ["will", "change", ..] => Ok("will-change"),
-
-        // https://tailwindcss.com/docs/fill
-        ["fill", ..] => Ok("fill"),
-
-        // https://tailwindcss.com/docs/stroke-width
-        ["stroke", rest] if rest.parse::<usize>().is_ok() => Ok("stroke-width"),
-        ["stroke"] if is_valid_length(arbitrary) => Ok("stroke-width"),
-
-        // https://tailwindcss.com/docs/stroke
-        ["stroke", ..]=> {
-            Ok("stroke")
-        },
-
-        // https://tailwindcss.com/docs/screen-readers
-        ["sr", "only"] | ["not", "sr", "only"] => Ok("screen-readers"),
-
-        // Typography plugin: https://github.com/tailwindlabs/tailwindcss-typography
-        ["prose"] => Ok("prose"),
-        ["not", "prose"] => Ok("not-prose"),
-        ["prose", ..] => Ok("prose"),
-
-        // https://tailwindcss.com/docs/forced-color-adjust
-        ["forced", "color", "adjust", "auto" | "none"] => Ok("forced-color-adjust"),
-
-        // v4: https://tailwindcss.com/docs/field-sizing
-        ["field", "sizing", "content" | "fixed"] => Ok("field-sizing"),
-
-        // v4: https://tailwindcss.com/docs/mask-image
-        ["mask", "none"] => Ok("mask-image"),
-        ["mask", "type", ..] => Ok("mask-type"),
-        ["mask", "position", ..] => Ok("mask-position"),
-        ["mask", "size", ..] => Ok("mask-size"),
-        ["mask", "repeat", ..] => Ok("mask-repeat"),
-        ["mask", "origin", ..] => Ok("mask-origin"),
-        ["mask", "clip", ..] => Ok("mask-clip"),
-        ["mask", "composite", ..] => Ok("mask-composite"),
-        // v4.1: directional gradient masks
-        ["mask", "t", ..] => Ok("mask-linear-t"),
-        ["mask", "r", ..] => Ok("mask-linear-r"),
-        ["mask", "b", ..] => Ok("mask-linear-b"),
-        ["mask", "l", ..] => Ok("mask-linear-l"),
-        // v4.1: radial/conic gradient masks
-        ["mask", "radial", ..] => Ok("mask-radial"),
-        ["mask", "conic", ..] => Ok("mask-conic"),
-        ["mask", ..] => Ok("mask-image"),
-
-        // https://tailwindcss.com/docs/hover-focus-and-other-states#styling-based-on-parent-state
-        ["group"] => Ok("group"),
-        ["group", ..] => Ok("group"), // named group: group/item (parser splits on - so group/input-group becomes ["group/input", "group"])
-        [first, ..] if first.starts_with("group/") => Ok("group"), // handles group/name when no - follows
-
-        // https://tailwindcss.com/docs/hover-focus-and-other-states#styling-based-on-sibling-state
-        ["peer"] => Ok("peer"),
-        ["peer", ..] => Ok("peer"), // named peer: peer/item
-        [first, ..] if first.starts_with("peer/") => Ok("peer"), // handles peer/name when no - follows
-
-        // BEM-style classes (e.g., toast__container, block__element--modifier)
-        // These are custom classes, not Tailwind utilities - pass them through
-        [first, ..] if first.contains("__") => Ok("custom-bem"),
-
-        _ => Err("Invalid Tailwind class"),
-    }
+fn is_empty_or_arbitrary_len(input: &str) -> bool {
+    input.is_empty() || is_arbitrary_len(input)
 }
 
 fn valid_blend(mode: &[&str]) -> bool {
@@ -980,17 +1041,6 @@ fn valid_blend(mode: &[&str]) -> bool {
     )
 }
 
-fn valid_trbl(mode: &[&str], arbitrary: &str, success: &'static str, error: &'static str) -> Result<&'static str> {
-    if mode.len() == 1 && valid_top_right_bottom_left(mode[0]) {
-        return Ok(success);
-    }
-    if is_valid_length(arbitrary) {
-        return Ok(success);
-    }
-
-    Err(error)
-}
-
 fn valid_top_right_bottom_left(mode: &str) -> bool {
     mode == "auto"
         || mode == "full"
@@ -1003,19 +1053,9 @@ fn valid_break_after(mode: &str) -> bool {
     matches!(mode, "auto" | "avoid" | "all" | "avoid-page" | "page" | "left" | "right" | "column")
 }
 
-// Need starts_with for this https://tailwindcss.com/docs/font-size#setting-the-line-height
+// The line-height in text-lg/7 is parsed as a modifier, see https://tailwindcss.com/docs/font-size#setting-the-line-height
 fn valid_text_size(mode: &str) -> bool {
-    mode == "base"
-        || mode.starts_with("xs")
-        || mode.ends_with("xs")
-        || mode.starts_with("sm")
-        || mode.ends_with("sm")
-        || mode.starts_with("md")
-        || mode.ends_with("md")
-        || mode.starts_with("lg")
-        || mode.ends_with("lg")
-        || mode.starts_with("xl")
-        || mode.ends_with("xl")
+    mode == "base" || is_t_shirt_size(mode)
 }
 
 fn parse_fraction_or_usize(input: &str) -> bool {
@@ -1053,6 +1093,25 @@ fn is_valid_color(input: &str) -> bool {
     validators::color::parse(input).is_ok()
 }
 
+// inherit, white, red-500
+fn is_color_name(rest: &[&str]) -> bool {
+    match rest {
+        ["inherit" | "current" | "transparent" | "black" | "white"] => true,
+        [color, shade] => COLOR_NAMES.contains(color) && COLOR_SHADES.contains(shade),
+        _ => false,
+    }
+}
+
+// The default palette, including the v4 neutrals.
+#[rustfmt::skip]
+const COLOR_NAMES: &[&str] = &[
+    "slate", "gray", "zinc", "neutral", "stone", "mauve", "olive", "mist", "taupe",
+    "red", "orange", "amber", "yellow", "lime", "green", "emerald", "teal",
+    "cyan", "sky", "blue", "indigo", "violet", "purple", "fuchsia", "pink", "rose",
+];
+
+const COLOR_SHADES: &[&str] = &["50", "100", "200", "300", "400", "500", "600", "700", "800", "900", "950"];
+
 fn is_arbitrary_value(input: &str) -> bool {
     validators::arbitrary::parse(input).is_ok()
 }
@@ -1110,6 +1169,11 @@ fn is_valid_arbitrary_value(input: &str, label: impl Fn(&str) -> bool, func: imp
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::merge::CollisionTable;
+
+    fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static str, &'static str> {
+        CollisionTable::default_ref().lookup(classes, Some(arbitrary)).ok_or("Invalid Tailwind class")
+    }
 
     #[test]
     fn test_len() {
@@ -1121,6 +1185,23 @@ mod test {
         assert!(!is_valid_length("--my-0"), "double negative");
     }
 
+    #[test]
+    fn property_groups_exist() {
+        let table = CollisionTable::default_ref();
+        for (property, id) in PROPERTIES {
+            assert!(table.group(id).is_some(), "{property}: {id} is not a class group");
+        }
+    }
+
+    #[test]
+    fn listed_groups_exist() {
+        let table = CollisionTable::default_ref();
+        let patterns = NEGATIVE_PATTERNS.iter().map(|(id, _)| id);
+        for id in NEGATIVE_GROUPS.iter().chain(patterns).chain(MODIFIER_GROUPS).chain(FRACTION_GROUPS) {
+            assert!(table.group(id).is_some(), "{id} is not a class group");
+        }
+    }
+
     #[test]
     fn parse_stroke() {
         let result = get_collision_id(&["stroke"], "10px");
@@ -1240,6 +1321,30 @@ mod test {
 
         let result = get_collision_id(&["border", "b"], "");
         assert_eq!(result, Ok("border-w-b"));
+
+        let result = get_collision_id(&["border", "r", "white"], "");
+        assert_eq!(result, Ok("border-color-r"));
+
+        let result = get_collision_id(&["border", "b", "red", "500"], "");
+        assert_eq!(result, Ok("border-color-b"));
+    }
+
+    #[test]
+    fn parse_decoration() {
+        let result = get_collision_id(&["decoration", "red", "500"], "");
+        assert_eq!(result, Ok("text-decoration-color"));
+
+        let result = get_collision_id(&["decoration", "current"], "");
+        assert_eq!(result, Ok("text-decoration-color"));
+
+        let result = get_collision_id(&["decoration"], "#fff");
+        assert_eq!(result, Ok("text-decoration-color"));
+
+        let result = get_collision_id(&["decoration", "2"], "");
+        assert_eq!(result, Ok("text-decoration-thickness"));
+
+        assert!(get_collision_id(&["decoration", "lg"], "").is_err());
+        assert!(get_collision_id(&["decoration", "red", "lg"], "").is_err());
     }
 
     #[test]
@@ -0,0 +1,270 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use super::{ClassDiagnostic, CollisionIdFn, CollisionTable, GetCollisionsFn, MergeExplanation, MergeOptions, Theme};
+use crate::ast::{InvalidClass, ParsedClass};
+
+thread_local! {
+    static SCOPED_MERGER: RefCell<Option<TwMerger>> = const { RefCell::new(None) };
+}
+
+/// A Tailwind class merger that owns its configuration.
+///
+/// Unlike [`crate::merge::set_merge_options`], any number of mergers can live side by side,
+/// e.g. one per design system.
+///
+/// ```
+/// use tw_merge::merge::*;
+///
+/// let merger = TwMerger::new(MergeOptions { prefix: "tw-", separator: ":" });
+/// assert_eq!(merger.merge(&["tw-p-2 hover:tw-p-2", "tw-p-4"]), "hover:tw-p-2 tw-p-4");
+///
+/// // Scope the merger used by `tw_merge!` on this thread.
+/// let _guard = merger.scope();
+/// assert_eq!(tw_merge::tw_merge!("tw-bg-black", "tw-bg-white"), "tw-bg-white");
+/// ```
+#[derive(Clone, Default)]
+pub struct TwMerger {
+    options: MergeOptions,
+    theme: Option<Arc<Theme>>,
+    table: Option<Arc<CollisionTable>>,
+    collision_id_fn: Option<Arc<dyn CollisionIdFn + Send + Sync>>,
+    collisions_fn: Option<Arc<dyn GetCollisionsFn + Send + Sync>>,
+}
+
+impl TwMerger {
+    /// Create a merger with the given options and the default collision rules.
+    pub fn new(options: MergeOptions) -> Self {
+        Self { options, theme: None, table: None, collision_id_fn: None, collisions_fn: None }
+    }
+
+    /// Use the custom values of a Tailwind theme, so custom tokens land in the correct group.
+    ///
+    /// See [`crate::merge::Theme`].
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(Arc::new(theme));
+        self
+    }
+
+    /// Use a custom table of class groups and collisions instead of the default one.
+    ///
+    /// See [`crate::merge::CollisionTable`].
+    pub fn with_collision_table(mut self, table: CollisionTable) -> Self {
+        self.table = Some(Arc::new(table));
+        self
+    }
+
+    /// Override the CollisionId of classes. Returning `None` falls back to the default rules.
+    ///
+    /// See [`crate::merge::tw_merge_override`].
+    pub fn with_collision_id_fn(mut self, collision_id_fn: impl CollisionIdFn + Send + Sync + 'static) -> Self {
+        self.collision_id_fn = Some(Arc::new(collision_id_fn));
+        self
+    }
+
+    /// Add or override the collisions of a CollisionId. Returning `None` falls back to the default rules.
+    ///
+    /// See [`crate::merge::tw_merge_override`].
+    pub fn with_collisions_fn(mut self, collisions_fn: impl GetCollisionsFn + Send + Sync + 'static) -> Self {
+        self.collisions_fn = Some(Arc::new(collisions_fn));
+        self
+    }
+
+    /// The options used by this merger.
+    pub fn options(&self) -> MergeOptions {
+        self.options
+    }
+
+    /// The theme used by this merger, if any.
+    pub fn theme(&self) -> Option<&Theme> {
+        self.theme.as_deref()
+    }
+
+    /// The table of class groups and collisions used by this merger.
+    pub fn collision_table(&self) -> &CollisionTable {
+        self.table.as_deref().unwrap_or_else(|| CollisionTable::default_ref())
+    }
+
+    /// Merges all the Tailwind classes in the provided strings, resolving conflicts.
+    pub fn merge(&self, class: &[&str]) -> String {
+        let mut merged = String::new();
+        self.merge_into(class, &mut merged);
+        merged
+    }
+
+    /// Merges the Tailwind classes into `out`, replacing its content but keeping its allocation.
+    ///
+    /// See [`crate::merge::merge_into`].
+    pub fn merge_into(&self, class: &[&str], out: &mut String) {
+        let table = self.collision_table();
+        super::tw_merge_override::merge_into_override(
+            class,
+            self.options,
+            table,
+            &MergerFns(self),
+            &MergerFns(self),
+            out,
+        )
+    }
+
+    /// Merges the Tailwind classes, borrowing the input if the merge doesn't change it.
+    ///
+    /// See [`crate::merge::merge_cow`].
+    pub fn merge_cow<'a>(&self, class: &'a str) -> Cow<'a, str> {
+        let table = self.collision_table();
+        super::tw_merge_override::merge_cow_override(class, self.options, table, &MergerFns(self), &MergerFns(self))
+    }
+
+    /// Explains how the Tailwind classes in the provided strings are merged.
+    ///
+    /// See [`crate::merge::merge_explain`].
+    pub fn explain<'a>(&self, class: &[&'a str]) -> MergeExplanation<'a> {
+        super::explain::explain_override(
+            class,
+            self.options,
+            self.collision_table(),
+            &MergerFns(self),
+            &MergerFns(self),
+        )
+    }
+
+    /// Sorts the Tailwind classes in the order of prettier-plugin-tailwindcss.
+    ///
+    /// See [`crate::merge::sort_classes`].
+    pub fn sort_classes(&self, class: &[&str]) -> String {
+        let table = self.collision_table();
+        super::sort_classes::sort_override(class, self.options, table, &MergerFns(self), &MergerFns(self))
+    }
+
+    /// Parses a single Tailwind class the way this merger interprets it.
+    ///
+    /// See [`crate::ast::parse_class`].
+    pub fn parse_class<'a>(&self, class: &'a str) -> Result<ParsedClass<'a>, InvalidClass<'a>> {
+        crate::ast::parse_class_override(class, self.options, self.collision_table(), &MergerFns(self))
+    }
+
+    /// Parses every whitespace separated class in the input the way this merger interprets it.
+    ///
+    /// See [`crate::ast::parse_classes`].
+    pub fn parse_classes<'a>(&self, input: &'a str) -> Vec<Result<ParsedClass<'a>, InvalidClass<'a>>> {
+        crate::ast::parse_classes_override(input, self.options, self.collision_table(), &MergerFns(self))
+    }
+
+    /// Merges the Tailwind classes, failing if any class is unknown or can't be parsed.
+    ///
+    /// See [`crate::merge::merge_strict`].
+    pub fn merge_strict<'a>(&self, class: &[&'a str]) -> Result<String, Vec<ClassDiagnostic<'a>>> {
+        super::diagnostics::into_result(self.merge_lenient(class))
+    }
+
+    /// Merges the Tailwind classes, returning the unknown classes next to the output.
+    ///
+    /// See [`crate::merge::merge_lenient`].
+    pub fn merge_lenient<'a>(&self, class: &[&'a str]) -> (String, Vec<ClassDiagnostic<'a>>) {
+        let table = self.collision_table();
+        super::diagnostics::lenient_override(class, self.options, table, &MergerFns(self), &MergerFns(self))
+    }
+
+    /// Use this merger for [`crate::tw_merge!`] and [`crate::merge::merge_classes`] on the current thread,
+    /// until the returned guard is dropped.
+    ///
+    /// Scopes can be nested, dropping the guard restores the previous merger.
+    #[must_use = "the merger is only in scope until the guard is dropped"]
+    pub fn scope(&self) -> TwMergerGuard {
+        let previous = SCOPED_MERGER.with_borrow_mut(|scoped| scoped.replace(self.clone()));
+        TwMergerGuard { previous, _not_send: PhantomData }
+    }
+
+    /// Merge with the merger in scope on the current thread, if any.
+    pub(crate) fn merge_scoped(class: &[&str]) -> Option<String> {
+        SCOPED_MERGER.with_borrow(|scoped| scoped.as_ref().map(|merger| merger.merge(class)))
+    }
+
+    /// Merge into `out` with the merger in scope on the current thread, if any.
+    pub(crate) fn merge_into_scoped(class: &[&str], out: &mut String) -> Option<()> {
+        SCOPED_MERGER.with_borrow(|scoped| scoped.as_ref().map(|merger| merger.merge_into(class, out)))
+    }
+
+    /// Merge without copying with the merger in scope on the current thread, if any.
+    pub(crate) fn merge_cow_scoped(class: &str) -> Option<Cow<'_, str>> {
+        SCOPED_MERGER.with_borrow(|scoped| scoped.as_ref().map(|merger| merger.merge_cow(class)))
+    }
+
+    /// Sort with the merger in scope on the current thread, if any.
+    pub(crate) fn sort_scoped(class: &[&str]) -> Option<String> {
+        SCOPED_MERGER.with_borrow(|scoped| scoped.as_ref().map(|merger| merger.sort_classes(class)))
+    }
+
+    /// Options of the merger in scope on the current thread, if any.
+    /// Parse a class with the merger in scope on the current thread, if any.
+    pub(crate) fn parse_class_scoped(class: &str) -> Option<Result<ParsedClass<'_>, InvalidClass<'_>>> {
+        SCOPED_MERGER.with_borrow(|scoped| scoped.as_ref().map(|merger| merger.parse_class(class)))
+    }
+
+    /// Parse the classes with the merger in scope on the current thread, if any.
+    pub(crate) fn parse_classes_scoped(input: &str) -> Option<Vec<Result<ParsedClass<'_>, InvalidClass<'_>>>> {
+        SCOPED_MERGER.with_borrow(|scoped| scoped.as_ref().map(|merger| merger.parse_classes(input)))
+    }
+
+    pub(crate) fn options_scoped() -> Option<MergeOptions> {
+        SCOPED_MERGER.with_borrow(|scoped| scoped.as_ref().map(|merger| merger.options))
+    }
+
+    /// Lenient merge with the merger in scope on the current thread, if any.
+    pub(crate) fn lenient_scoped<'a>(class: &[&'a str]) -> Option<(String, Vec<ClassDiagnostic<'a>>)> {
+        SCOPED_MERGER.with_borrow(|scoped| scoped.as_ref().map(|merger| merger.merge_lenient(class)))
+    }
+
+    /// Explain with the merger in scope on the current thread, if any.
+    pub(crate) fn explain_scoped<'a>(class: &[&'a str]) -> Option<MergeExplanation<'a>> {
+        SCOPED_MERGER.with_borrow(|scoped| scoped.as_ref().map(|merger| merger.explain(class)))
+    }
+}
+
+impl std::fmt::Debug for TwMerger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TwMerger")
+            .field("options", &self.options)
+            .field("theme", &self.theme)
+            .field("table", &self.table)
+            .field("collision_id_fn", &self.collision_id_fn.is_some())
+            .field("collisions_fn", &self.collisions_fn.is_some())
+            .finish()
+    }
+}
+
+/// Keeps a [`TwMerger`] in scope for the current thread. See [`TwMerger::scope`].
+pub struct TwMergerGuard {
+    previous: Option<TwMerger>,
+    // The scope is per thread, so the guard must be dropped on the thread that created it.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for TwMergerGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        SCOPED_MERGER.with_borrow_mut(|scoped| *scoped = previous);
+    }
+}
+
+struct MergerFns<'a>(&'a TwMerger);
+
+impl CollisionIdFn for MergerFns<'_> {
+    fn apply(&self, elements: &[&str], arbitrary: Option<&str>) -> Option<&'static str> {
+        let MergerFns(merger) = self;
+        // Explicit overrides take precedence over the theme.
+        merger
+            .collision_id_fn
+            .as_ref()
+            .and_then(|collision_id_fn| collision_id_fn.apply(elements, arbitrary))
+            .or_else(|| merger.theme.as_ref()?.collision_id_in(merger.collision_table(), elements, arbitrary))
+    }
+}
+
+impl GetCollisionsFn for MergerFns<'_> {
+    fn apply(&self, collision_id: &str) -> Option<&[&'static str]> {
+        self.0.collisions_fn.as_ref()?.apply(collision_id)
+    }
+}
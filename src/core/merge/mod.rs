@@ -1,10 +1,29 @@
+use std::borrow::Cow;
+
+#[cfg(feature = "cache")]
+pub(crate) mod cache;
+pub(crate) mod collision_table;
 pub(crate) mod config;
+pub(crate) mod diagnostics;
+pub(crate) mod explain;
 pub(crate) mod get_collision_id;
 pub(crate) mod get_collisions;
+pub(crate) mod merger;
+pub(crate) mod sort_classes;
+pub(crate) mod sort_variants;
+pub(crate) mod theme;
 pub(crate) mod tw_merge_override;
 pub(crate) mod validators;
 
+#[cfg(feature = "cache")]
+pub use cache::*;
+pub use collision_table::*;
 pub use config::*;
+pub use diagnostics::*;
+pub use explain::*;
+pub use merger::*;
+pub use sort_classes::{sort_classes, sort_classes_options};
+pub use theme::*;
 pub use tw_merge_override::tw_merge_override;
 
 /// Merges all the Tailwind classes, resolving conflicts.
@@ -15,9 +34,23 @@ pub use tw_merge_override::tw_merge_override;
 ///
 /// If you want to set global options use [`crate::merge::set_merge_options`].
 ///
+/// If you want to use a specific [`crate::merge::TwMerger`], pass it before `=>`
+/// or scope it with [`crate::merge::TwMerger::scope`].
+///
 /// If you want a custom type to be used with this macro, implement the [`crate::MaybeIntoTailwindClass`] trait.
+///
+/// ```
+/// use tw_merge::{tw_merge, merge::*};
+///
+/// let merger = TwMerger::new(MergeOptions { prefix: "tw-", separator: ":" });
+/// assert_eq!(tw_merge!(merger => "tw-p-2", "tw-p-4"), "tw-p-4");
+/// ```
 #[macro_export]
 macro_rules! tw_merge {
+    ($merger:expr => $($item:expr),+ $(,)?) => {{
+        let joined = $crate::tw_join!($($item),+);
+        $crate::merge::TwMerger::merge(&$merger, &[joined.as_str()])
+    }};
     ($($item:expr),+ $(,)?) => {{
         let joined = $crate::tw_join!($($item),+);
         $crate::merge::merge_classes(joined.as_str())
@@ -27,19 +60,19 @@ macro_rules! tw_merge {
 /// Internal function triggered by the `tw_merge!` macro.
 /// Merges all the Tailwind classes in the string, resolving conflicts.
 ///
-/// If you need custom options use [`tw_merge_options`].
+/// Uses the [`TwMerger`] in scope, if any. If you need custom options use [`tw_merge_options`].
 #[inline]
 pub fn merge_classes(class: impl AsRef<str>) -> String {
-    tw_merge_slice_options(&[class.as_ref()], Default::default())
+    tw_merge_slice(&[class.as_ref()])
 }
 
 /// Merges all the Tailwind classes in the provided strings, resolving conflicts.
 /// Useful to avoid collecting all the strings into a single string.
 ///
-/// If you need custom options use [`tw_merge_slice_options`].
+/// Uses the [`TwMerger`] in scope, if any. If you need custom options use [`tw_merge_slice_options`].
 #[inline]
 pub fn tw_merge_slice(class: &[&str]) -> String {
-    tw_merge_slice_options(class, Default::default())
+    TwMerger::merge_scoped(class).unwrap_or_else(|| tw_merge_slice_options(class, Default::default()))
 }
 
 /// Merges all the Tailwind classes, resolving conflicts, with the provided options.
@@ -59,7 +92,7 @@ pub fn tw_merge_slice(class: &[&str]) -> String {
 /// ```
 #[inline]
 pub fn tw_merge_options(class: impl AsRef<str>, options: MergeOptions) -> String {
-    tw_merge_override::tw_merge_override(&[class.as_ref()], options, |_: &[&str], _: Option<&str>| None, |_: &str| None)
+    tw_merge_slice_options(&[class.as_ref()], options)
 }
 
 /// Merges all the Tailwind classes in the provided strings, resolving conflicts.
@@ -82,6 +115,75 @@ pub fn tw_merge_options(class: impl AsRef<str>, options: MergeOptions) -> String
 /// ```
 #[inline]
 pub fn tw_merge_slice_options(class: &[&str], options: MergeOptions) -> String {
+    #[cfg(feature = "cache")]
+    return MergeCache::global().merge_options(class, options);
+    #[cfg(not(feature = "cache"))]
+    merge_uncached(class, options)
+}
+
+/// Merges all the Tailwind classes in the string, borrowing the input if the merge doesn't change it:
+/// no class is dropped and the classes are already separated by a single space.
+///
+/// Static component classes rarely conflict, so this usually skips allocating the output.
+/// Unlike [`merge_classes`], this never uses the [`MergeCache`](crate::merge::MergeCache).
+///
+/// Uses the [`TwMerger`] in scope, if any. If you need custom options use [`merge_cow_options`].
+///
+/// ```
+/// use std::borrow::Cow;
+/// use tw_merge::merge::*;
+///
+/// assert!(matches!(merge_cow("flex p-4 text-white"), Cow::Borrowed("flex p-4 text-white")));
+/// assert_eq!(merge_cow("flex p-2 p-4"), Cow::<str>::Owned("flex p-4".to_string()));
+/// assert_eq!(merge_cow(" flex  p-4 "), Cow::<str>::Owned("flex p-4".to_string()));
+/// ```
+pub fn merge_cow(class: &str) -> Cow<'_, str> {
+    TwMerger::merge_cow_scoped(class).unwrap_or_else(|| merge_cow_options(class, Default::default()))
+}
+
+/// Merges all the Tailwind classes in the string with the provided options,
+/// borrowing the input if the merge doesn't change it.
+///
+/// If you don't need custom options use [`merge_cow`].
+pub fn merge_cow_options(class: &str, options: MergeOptions) -> Cow<'_, str> {
+    let table = CollisionTable::default_ref();
+    tw_merge_override::merge_cow_override(class, options, table, &|_: &[&str], _: Option<&str>| None, &|_: &str| None)
+}
+
+/// Merges all the Tailwind classes in the string into `out`, replacing its content but keeping its allocation.
+/// Useful to merge many class strings in a loop with a single buffer.
+///
+/// Unlike [`merge_classes`], this never uses the [`MergeCache`](crate::merge::MergeCache).
+///
+/// Uses the [`TwMerger`] in scope, if any. If you need custom options use [`merge_into_options`].
+///
+/// ```
+/// use tw_merge::merge::*;
+///
+/// let mut buffer = String::new();
+/// for class in ["p-2 p-4", "flex block"] {
+///     merge_into(class, &mut buffer);
+///     println!("{buffer}");
+/// }
+/// assert_eq!(buffer, "block");
+/// ```
+pub fn merge_into(class: &str, out: &mut String) {
+    if TwMerger::merge_into_scoped(&[class], out).is_none() {
+        merge_into_options(class, Default::default(), out)
+    }
+}
+
+/// Merges all the Tailwind classes in the string into `out` with the provided options,
+/// replacing its content but keeping its allocation.
+///
+/// If you don't need custom options use [`merge_into`].
+pub fn merge_into_options(class: &str, options: MergeOptions, out: &mut String) {
+    let table = CollisionTable::default_ref();
+    let collision_id_fn = |_: &[&str], _: Option<&str>| None;
+    tw_merge_override::merge_into_override(&[class], options, table, &collision_id_fn, &|_: &str| None, out)
+}
+
+pub(crate) fn merge_uncached(class: &[&str], options: MergeOptions) -> String {
     tw_merge_override::tw_merge_override(class, options, |_: &[&str], _: Option<&str>| None, |_: &str| None)
 }
 
@@ -113,14 +215,14 @@ where
 /// e.g. "flex-row" should probably collide with "flex-col"
 pub trait GetCollisionsFn {
     /// Return list of CollisionIds that collide with the given CollisionId.
-    fn apply(&self, collision_id: &str) -> Option<Vec<&'static str>>;
+    fn apply(&self, collision_id: &str) -> Option<&[&'static str]>;
 }
 
 impl<F> GetCollisionsFn for F
 where
-    F: Fn(&str) -> Option<Vec<&'static str>>,
+    F: Fn(&str) -> Option<&'static [&'static str]>,
 {
-    fn apply(&self, collision_id: &str) -> Option<Vec<&'static str>> {
+    fn apply(&self, collision_id: &str) -> Option<&[&'static str]> {
         self(collision_id)
     }
 }
@@ -0,0 +1,228 @@
+use super::get_collisions::get_modifier_collisions;
+use super::sort_variants::sort_variants;
+use super::tw_merge_override::{Resolved, resolve};
+use super::{CollisionIdFn, CollisionTable, GetCollisionsFn, MergeOptions, TwMerger};
+use crate::ast::AstStyle;
+
+/// Class groups of the components layer, sorted before the utilities.
+const COMPONENTS: &[&str] = &["container", "prose", "not-prose"];
+
+/// CollisionIds in the order Tailwind emits their CSS properties.
+/// Groups missing from the list are sorted after it, in the order of the [`CollisionTable`].
+///
+/// <https://github.com/tailwindlabs/tailwindcss/blob/main/packages/tailwindcss/src/property-order.ts>
+#[rustfmt::skip]
+const UTILITY_ORDER: &[&str] = &[
+    "screen-readers", "pointer-events", "visibility", "position",
+    "inset", "inset-x", "inset-y", "start", "end", "top", "right", "bottom", "left",
+    "isolation", "z-index", "order",
+    "col-start-end", "col-start", "col-end", "row-start-end", "row-start", "row-end",
+    "float", "clear",
+    "margin", "margin-x", "margin-y", "margin-start", "margin-end",
+    "margin-top", "margin-right", "margin-bottom", "margin-left",
+    "box-sizing", "line-clamp", "display", "field-sizing", "aspect",
+    "size", "height", "max-height", "min-height", "width", "max-width", "min-width",
+    "flex", "flex-shrink", "flex-grow", "flex-basis",
+    "table-layout", "caption-side", "border-collapse", "border-spacing", "border-spacing-x", "border-spacing-y",
+    "transform-origin", "translate", "translate-x", "translate-y", "translate-z",
+    "scale", "scale-x", "scale-y", "scale-z", "rotate", "rotate-x", "rotate-y", "rotate-z", "skew", "skew-x", "skew-y",
+    "transform", "transform-3d", "backface-visibility", "perspective", "perspective-origin",
+    "animate", "cursor", "touch", "touch-x", "touch-y", "touch-pz", "user-select", "resize",
+    "scroll-snap-type", "scroll-snap-align", "scroll-snap-stop", "scroll-margin", "scroll-padding",
+    "list-style-position", "list-style-type", "list-style-image", "appearance", "columns",
+    "break-before", "break-inside", "break-after",
+    "auto-cols", "grid-template-columns", "grid-auto-flow", "auto-rows", "grid-template-rows",
+    "flex-direction", "flex-wrap", "place-content", "place-items", "align-content", "align-items",
+    "justify-content", "justify-items",
+    "gap", "gap-x", "gap-y", "space-x", "space-y", "space-x-reverse", "space-y-reverse",
+    "divide-x", "divide-y", "divide-x-reverse", "divide-y-reverse", "divide-style", "divide-color",
+    "place-self", "align-self", "justify-self",
+    "overflow", "overscroll-behavior", "scroll-behavior",
+    "rounded", "rounded-s", "rounded-e", "rounded-t", "rounded-r", "rounded-b", "rounded-l",
+    "rounded-ss", "rounded-se", "rounded-ee", "rounded-es", "rounded-tl", "rounded-tr", "rounded-br", "rounded-bl",
+    "border-w", "border-w-x", "border-w-y", "border-w-s", "border-w-t", "border-w-r", "border-w-b", "border-w-l",
+    "border-style",
+    "border-color", "border-color-x", "border-color-y", "border-color-s", "border-color-e",
+    "border-color-t", "border-color-r", "border-color-b", "border-color-l",
+    "background-color", "background-image", "from", "via", "to",
+    "box-decoration-break", "background-size", "background-attachment", "background-clip",
+    "background-position", "background-repeat", "background-origin",
+    "mask-image", "mask-linear-t", "mask-linear-r", "mask-linear-b", "mask-linear-l", "mask-radial", "mask-conic",
+    "mask-composite", "mask-type", "mask-size", "mask-clip", "mask-position", "mask-repeat", "mask-origin",
+    "fill", "stroke", "stroke-width", "object-fit", "object-position",
+    "padding", "padding-x", "padding-y", "padding-top", "padding-right", "padding-bottom", "padding-left",
+    "text-align", "text-indent", "vertical-align", "font-family", "font-size", "line-height", "font-weight",
+    "letter-spacing", "text-wrap", "overflow-wrap", "word-break", "text-overflow", "hyphens", "whitespace",
+    "text-color", "text-transform", "font-style", "font-stretch",
+    "fvn-normal", "fvn-ordinal", "fvn-slashed-zero", "fvn-figure", "fvn-spacing", "fvn-fraction",
+    "text-decoration", "text-decoration-color", "text-decoration-style", "text-decoration-thickness",
+    "text-underline-offset", "font-smoothing", "caret-color", "accent-color", "color-scheme",
+    "opacity", "background-blend-mode", "mix-blend-mode",
+    "box-shadow", "box-shadow-color", "inset-shadow", "inset-shadow-color", "ring-width", "ring-color",
+    "inset-ring", "inset-ring-color", "text-shadow", "text-shadow-color", "ring-offset-width", "ring-offset-color",
+    "outline-style", "outline-width", "outline-offset", "outline-color",
+    "blur", "brightness", "contrast", "drop-shadow", "drop-shadow-color", "grayscale", "hue-rotate", "invert",
+    "saturate", "sepia",
+    "backdrop-blur", "backdrop-brightness", "backdrop-contrast", "backdrop-grayscale", "backdrop-hue-rotate",
+    "backdrop-invert", "backdrop-opacity", "backdrop-saturate", "backdrop-sepia",
+    "transition-property", "transition-behavior", "transition-delay", "transition-duration",
+    "transition-timing-function", "will-change", "content", "forced-color-adjust",
+];
+
+/// Variants in the order Tailwind registers them. Functional variants like `group-*` or `max-*`
+/// are listed by their root. Unknown and arbitrary variants are sorted last.
+#[rustfmt::skip]
+const VARIANT_ORDER: &[&str] = &[
+    "*", "not", "group", "peer",
+    "first-letter", "first-line", "marker", "selection", "file", "placeholder", "backdrop", "before", "after",
+    "first", "last", "only", "odd", "even", "first-of-type", "last-of-type", "only-of-type",
+    "visited", "target", "open", "default", "checked", "indeterminate", "placeholder-shown", "autofill",
+    "optional", "required", "valid", "invalid", "user-valid", "user-invalid", "in-range", "out-of-range",
+    "read-only", "empty", "focus-within", "hover", "focus", "focus-visible", "active", "enabled", "disabled",
+    "inert", "in", "has", "aria", "data", "nth", "nth-last", "nth-of-type", "nth-last-of-type", "supports",
+    "motion-safe", "motion-reduce", "contrast-more", "contrast-less",
+    "max", "sm", "md", "lg", "xl", "2xl", "min",
+    "portrait", "landscape", "ltr", "rtl", "dark", "print", "forced-colors", "starting",
+];
+
+/// Sorts the Tailwind classes in the order of prettier-plugin-tailwindcss.
+///
+/// - Classes the merger doesn't know come first, in their original order.
+/// - Classes without variants, components (`container`) before utilities, in the order Tailwind emits their CSS.
+/// - Classes with variants, grouped by variant in the order Tailwind registers them.
+///
+/// Classes that conflict keep their relative order, so the sort never changes which class wins a conflict:
+/// `px-2 p-4` stays as it is, even though `padding` is sorted before `padding-x`.
+///
+/// Uses the [`TwMerger`] in scope, if any. If you need custom options use [`sort_classes_options`].
+///
+/// ```
+/// use tw_merge::merge::*;
+///
+/// assert_eq!(
+///     sort_classes("hover:bg-red-500 text-white my-class md:p-2 p-4 flex"),
+///     "my-class flex p-4 text-white hover:bg-red-500 md:p-2"
+/// );
+/// ```
+pub fn sort_classes(class: &str) -> String {
+    TwMerger::sort_scoped(&[class]).unwrap_or_else(|| sort_classes_options(class, Default::default()))
+}
+
+/// Sorts the Tailwind classes in the order of prettier-plugin-tailwindcss, with the provided options.
+///
+/// If you don't need custom options use [`sort_classes`].
+pub fn sort_classes_options(class: &str, options: MergeOptions) -> String {
+    let table = CollisionTable::default_ref();
+    sort_override(&[class], options, table, &|_: &[&str], _: Option<&str>| None, &|_: &str| None)
+}
+
+pub(crate) fn sort_override(
+    class: &[&str],
+    options: MergeOptions,
+    table: &CollisionTable,
+    collision_id_fn: &impl CollisionIdFn,
+    collisions_fn: &impl GetCollisionsFn,
+) -> String {
+    let resolved = resolve(class, options, table, collision_id_fn, collisions_fn);
+    let keys: Vec<SortKey> = resolved.iter().map(|resolved| SortKey::new(resolved, table)).collect();
+    let variants: Vec<Vec<&str>> = resolved
+        .iter()
+        .map(|resolved| resolved.style.as_ref().map_or(Vec::new(), |style| sort_variants(&style.variants)))
+        .collect();
+    let collide = |a: usize, b: usize| {
+        let (Some(style), Some(id), Some(other_style), Some(other_id)) =
+            (&resolved[a].style, resolved[a].collision_id, &resolved[b].style, resolved[b].collision_id)
+        else {
+            return false;
+        };
+        let knocks_out = |style: &AstStyle, id: &str, other_id: &str| {
+            id == other_id
+                || collisions_fn
+                    .apply(id)
+                    .map_or_else(|| table.collisions(id).contains(&other_id), |ids| ids.contains(&other_id))
+                || style.modifier.is_some() && get_modifier_collisions(id).contains(&other_id)
+        };
+        style.important == other_style.important
+            && variants[a] == variants[b]
+            && (knocks_out(style, id, other_id) || knocks_out(other_style, other_id, id))
+    };
+
+    // A stable sort, except that a class never moves before an earlier class it collides with.
+    let mut remaining: Vec<usize> = (0..resolved.len()).collect();
+    let mut sorted = Vec::with_capacity(resolved.len());
+    while !remaining.is_empty() {
+        let movable =
+            |position: &usize| remaining[..*position].iter().all(|&earlier| !collide(earlier, remaining[*position]));
+        // The first remaining class is always movable, and `min_by_key` keeps the first of equal keys.
+        let next = (0..remaining.len()).filter(movable).min_by_key(|&position| &keys[remaining[position]]).unwrap_or(0);
+        sorted.push(resolved[remaining.remove(next)].source);
+    }
+    sorted.join(" ")
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum SortKey {
+    Unknown,
+    Known {
+        has_variants: bool,
+        is_utility: bool,
+        // Ranks of the variants, highest first: like Tailwind's variant bits, the latest variant decides.
+        variants: Vec<usize>,
+        group: usize,
+    },
+}
+
+impl SortKey {
+    fn new(resolved: &Resolved, table: &CollisionTable) -> Self {
+        let (Some(style), Some(collision_id)) = (&resolved.style, resolved.collision_id) else {
+            return Self::Unknown;
+        };
+        let mut variants: Vec<usize> = style.variants.iter().map(|variant| variant_rank(variant)).collect();
+        variants.sort_unstable_by(|a, b| b.cmp(a));
+        // Arbitrary properties like `[mask-type:alpha]` are not in the table and go last.
+        let group = match UTILITY_ORDER.iter().position(|id| *id == collision_id) {
+            Some(position) => position,
+            None => UTILITY_ORDER.len() + table.rule_position(collision_id).unwrap_or(usize::MAX - UTILITY_ORDER.len()),
+        };
+        Self::Known {
+            has_variants: !variants.is_empty(),
+            is_utility: !COMPONENTS.contains(&collision_id),
+            variants,
+            group,
+        }
+    }
+}
+
+fn variant_rank(variant: &str) -> usize {
+    if let Some(rank) = VARIANT_ORDER.iter().position(|known| *known == variant) {
+        return rank;
+    }
+    // `group-hover` is a `group` variant, `nth-last-of-type-2` a `nth-last-of-type` one.
+    VARIANT_ORDER
+        .iter()
+        .enumerate()
+        .filter(|(_, known)| variant.strip_prefix(**known).is_some_and(|rest| rest.starts_with('-')))
+        .max_by_key(|(_, known)| known.len())
+        .map_or(VARIANT_ORDER.len(), |(rank, _)| rank)
+}
+
+/* ========================================================== */
+/*                       🧪 TESTS 🧪                          */
+/* ========================================================== */
+
+#[test]
+fn order_lists_known_groups() {
+    let table = CollisionTable::default();
+    for id in UTILITY_ORDER.iter().chain(COMPONENTS) {
+        assert!(table.group(id).is_some(), "{id} is not a class group");
+    }
+}
+
+#[test]
+fn functional_variants() {
+    assert_eq!(variant_rank("group-hover"), variant_rank("group"));
+    assert_eq!(variant_rank("nth-last-of-type-2"), variant_rank("nth-last-of-type"));
+    assert_eq!(variant_rank("max-md"), variant_rank("max"));
+    assert_eq!(variant_rank("[&>*]"), VARIANT_ORDER.len());
+    assert!(variant_rank("hover") < variant_rank("md"));
+}
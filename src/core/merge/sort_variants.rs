@@ -0,0 +1,66 @@
+/// Sorts variants into a canonical order, so `hover:md:` and `md:hover:` target the same selector.
+///
+/// Order-sensitive variants stay where they are and only the runs between them are sorted.
+/// <https://github.com/dcastil/tailwind-merge/blob/main/src/lib/parse-class-name.ts>
+pub(crate) fn sort_variants<'a>(variants: &[&'a str]) -> Vec<&'a str> {
+    let mut sorted = Vec::with_capacity(variants.len());
+    sort_variants_into(variants, &mut sorted);
+    sorted
+}
+
+/// Same as [`sort_variants`], reusing the `sorted` buffer.
+pub(crate) fn sort_variants_into<'a>(variants: &[&'a str], sorted: &mut Vec<&'a str>) {
+    sorted.clear();
+    sorted.extend_from_slice(variants);
+
+    let mut start = 0;
+    for end in 0..=sorted.len() {
+        if end == sorted.len() || is_order_sensitive(sorted[end]) {
+            sorted[start..end].sort_unstable();
+            start = end + 1;
+        }
+    }
+}
+
+fn is_order_sensitive(variant: &str) -> bool {
+    variant.starts_with('[')
+        || variant.starts_with("group-")
+        || variant.starts_with("peer-")
+        || matches!(
+            variant,
+            "*" | "**"
+                | "after"
+                | "backdrop"
+                | "before"
+                | "details-content"
+                | "file"
+                | "first-letter"
+                | "first-line"
+                | "marker"
+                | "placeholder"
+                | "selection"
+        )
+}
+
+/* ========================================================== */
+/*                       🧪 TESTS 🧪                          */
+/* ========================================================== */
+
+#[test]
+fn sorts_plain_variants() {
+    assert_eq!(sort_variants(&["md", "hover"]), vec!["hover", "md"]);
+    assert_eq!(sort_variants(&["hover", "md"]), vec!["hover", "md"]);
+    assert_eq!(sort_variants(&["focus", "dark", "data-[open]"]), vec!["dark", "data-[open]", "focus"]);
+}
+
+#[test]
+fn keeps_order_sensitive_variants_in_place() {
+    assert_eq!(
+        sort_variants(&["hover", "dark", "[&>*]", "md", "focus"]),
+        vec!["dark", "hover", "[&>*]", "focus", "md"]
+    );
+    assert_eq!(sort_variants(&["hover", "*", "dark"]), vec!["hover", "*", "dark"]);
+    assert_eq!(sort_variants(&["md", "before", "hover"]), vec!["md", "before", "hover"]);
+    assert_eq!(sort_variants(&["md", "group-hover", "dark"]), vec!["md", "group-hover", "dark"]);
+    assert_eq!(sort_variants(&["md", "peer-checked/label", "dark"]), vec!["md", "peer-checked/label", "dark"]);
+}
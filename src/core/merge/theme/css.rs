@@ -0,0 +1,241 @@
+use std::path::Path;
+
+use nom::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_till, take_till1, take_until, take_while1};
+use nom::character::complete::{anychar, char, multispace1};
+use nom::combinator::{map, opt, recognize, value};
+use nom::multi::many0;
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+
+use super::{Theme, ThemeError};
+
+// Longest namespace first, `--text-shadow-*` is not a font size.
+const NAMESPACES: &[&str] = &[
+    "inset-shadow",
+    "drop-shadow",
+    "text-shadow",
+    "shadow",
+    "color",
+    "text",
+    "radius",
+    "spacing",
+    "breakpoint",
+    "container",
+];
+
+impl Theme {
+    /// Load the custom values of every `@theme` block of a Tailwind v4 stylesheet.
+    ///
+    /// Reads the `--color-*`, `--text-*`, `--shadow-*`, `--inset-shadow-*`, `--drop-shadow-*`, `--text-shadow-*`,
+    /// `--radius-*`, `--spacing-*`, `--breakpoint-*` and `--container-*` namespaces, other variables are ignored.
+    ///
+    /// ```
+    /// use tw_merge::merge::*;
+    ///
+    /// let theme = Theme::from_css(
+    ///     "@import 'tailwindcss';
+    ///     @theme {
+    ///         --color-brand: oklch(0.72 0.11 178);
+    ///         --text-display: 4rem;
+    ///         --text-display--line-height: 1.1;
+    ///     }",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(theme.colors.contains("brand"));
+    /// assert!(theme.font_size.contains("display"));
+    /// ```
+    pub fn from_css(css: &str) -> Result<Self, ThemeError> {
+        let mut theme = Self::default();
+        let mut found = false;
+        let mut input = css;
+        while let Some(start) = find_theme(input) {
+            let block = &input[start..];
+            let (rest, body) = theme_block(block).map_err(|_| syntax_error(css, block, UNTERMINATED_BLOCK))?;
+            found = true;
+            let (remaining, names) = theme_body(body).map_err(|_| syntax_error(css, body, EXPECTED_DECLARATION))?;
+            if !remaining.is_empty() {
+                return Err(syntax_error(css, remaining, EXPECTED_DECLARATION));
+            }
+            names.into_iter().flatten().for_each(|name| theme.add_variable(name));
+            input = rest;
+        }
+
+        if found { Ok(theme) } else { Err(ThemeError::NotFound) }
+    }
+
+    /// Load the `@theme` blocks of a Tailwind v4 stylesheet, see [`Theme::from_css`].
+    pub fn from_css_file(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        Self::from_css(&std::fs::read_to_string(path)?)
+    }
+
+    // --color-brand-500 => colors: brand-500
+    fn add_variable(&mut self, name: &str) {
+        // --text-display--line-height configures --text-display
+        if name.contains("--") {
+            return;
+        }
+        let Some((namespace, value)) = NAMESPACES
+            .iter()
+            .find_map(|namespace| Some((*namespace, name.strip_prefix(namespace)?.strip_prefix('-')?)))
+        else {
+            return;
+        };
+        // --color-*: initial; resets the namespace
+        if value == "*" {
+            return;
+        }
+        let scale = match namespace {
+            "color" => &mut self.colors,
+            "text" => &mut self.font_size,
+            "radius" => &mut self.radius,
+            "spacing" => &mut self.spacing,
+            "breakpoint" => &mut self.breakpoints,
+            "container" => &mut self.containers,
+            "inset-shadow" => &mut self.inset_shadow,
+            "drop-shadow" => &mut self.drop_shadow,
+            "text-shadow" => &mut self.text_shadow,
+            _ => &mut self.shadow,
+        };
+        scale.insert(value.to_string());
+    }
+}
+
+const EXPECTED_DECLARATION: &str = "expected a `--variable: value;` declaration";
+const UNTERMINATED_BLOCK: &str = "expected a `{ ... }` block after `@theme`";
+
+fn syntax_error(css: &str, at: &str, message: &'static str) -> ThemeError {
+    let offset = at.as_ptr() as usize - css.as_ptr() as usize;
+    ThemeError::Syntax { offset, message }
+}
+
+// Offset of the next `@theme` outside of comments and strings.
+fn find_theme(css: &str) -> Option<usize> {
+    let mut rest = css;
+    while !rest.is_empty() {
+        if rest.starts_with("@theme") {
+            return Some(css.len() - rest.len());
+        }
+        rest = match alt((comment, string, escape))(rest) {
+            Ok((rest, _)) => rest,
+            // An unterminated comment or string runs to the end.
+            Err(_) if rest.starts_with(['"', '\'', '\\']) || rest.starts_with("/*") => return None,
+            Err(_) => &rest[rest.chars().next()?.len_utf8()..],
+        };
+    }
+    None
+}
+
+// @theme inline { ... }
+fn theme_block(input: &str) -> IResult<&str, &str> {
+    let (input, _) = tag("@theme")(input)?;
+    let (input, _) = take_till(|c| c == '{' || c == ';')(input)?;
+    delimited(char('{'), block_content, char('}'))(input)
+}
+
+// Everything up to the unbalanced `}`.
+fn block_content(input: &str) -> IResult<&str, &str> {
+    let braces = recognize(delimited(char('{'), block_content, char('}')));
+    let text = take_till1(|c| matches!(c, '{' | '}' | '"' | '\'' | '\\' | '/'));
+    recognize(many0(alt((comment, string, escape, braces, text, tag("/")))))(input)
+}
+
+// Variable names, `None` for nested blocks like `@keyframes`.
+fn theme_body(input: &str) -> IResult<&str, Vec<Option<&str>>> {
+    terminated(many0(preceded(trivia, alt((map(declaration, Some), value(None, nested_block))))), trivia)(input)
+}
+
+// --color-brand: #fff;
+fn declaration(input: &str) -> IResult<&str, &str> {
+    let (input, name) = preceded(tag("--"), take_while1(|c: char| c.is_alphanumeric() || "-_*.".contains(c)))(input)?;
+    let (input, _) = tuple((trivia, char(':'), declaration_value, opt(char(';'))))(input)?;
+    Ok((input, name))
+}
+
+// Everything up to the `;` or `}` ending the declaration, `url("data:image/svg+xml;...")` included.
+fn declaration_value(input: &str) -> IResult<&str, &str> {
+    let text = take_till1(|c| matches!(c, ';' | '{' | '}' | '"' | '\'' | '\\' | '(' | '/'));
+    recognize(many0(alt((comment, string, escape, parens, text, tag("/")))))(input)
+}
+
+// (...), a `;` inside is part of the value: `url(data:image/svg+xml;utf8,...)`
+fn parens(input: &str) -> IResult<&str, &str> {
+    let text = take_till1(|c| matches!(c, '(' | ')' | '"' | '\'' | '\\'));
+    recognize(delimited(char('('), many0(alt((string, escape, parens, text))), char(')')))(input)
+}
+
+// "..." or '...'
+fn string(input: &str) -> IResult<&str, &str> {
+    let error = || nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Char));
+    let quote = input.chars().next().filter(|c| matches!(c, '"' | '\'')).ok_or_else(error)?;
+    let mut escaped = false;
+    for (index, c) in input.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == quote => return Ok((&input[index + 1..], &input[..index + 1])),
+            _ => {}
+        }
+    }
+    Err(error())
+}
+
+// \; or \}
+fn escape(input: &str) -> IResult<&str, &str> {
+    recognize(pair(char('\\'), anychar))(input)
+}
+
+// /* ... */
+fn comment(input: &str) -> IResult<&str, &str> {
+    recognize(delimited(tag("/*"), take_until("*/"), tag("*/")))(input)
+}
+
+// @keyframes spin { ... }
+fn nested_block(input: &str) -> IResult<&str, &str> {
+    let prelude = take_till1(|c| c == '{' || c == '}' || c == ';');
+    recognize(pair(prelude, delimited(char('{'), block_content, char('}'))))(input)
+}
+
+// Whitespace and comments
+fn trivia(input: &str) -> IResult<&str, ()> {
+    value((), many0(alt((multispace1, comment))))(input)
+}
+
+/* ========================================================== */
+/*                       🧪 TESTS 🧪                          */
+/* ========================================================== */
+
+#[test]
+fn parse_declaration() {
+    assert_eq!(declaration("--color-brand : rgb(0 0 0 / 50%);x"), Ok(("x", "color-brand")));
+    assert_eq!(declaration("--text-xs--line-height: calc(1 / 0.75)"), Ok(("", "text-xs--line-height")));
+    assert!(declaration("color: red;").is_err());
+
+    // A `;` or `}` inside strings and url(...) doesn't end the value.
+    let svg = r#"--bg-icon: url("data:image/svg+xml;utf8,<svg>}</svg>");x"#;
+    assert_eq!(declaration(svg), Ok(("x", "bg-icon")));
+    assert_eq!(declaration("--bg-icon: url(data:image/png;base64,AAAA);x"), Ok(("x", "bg-icon")));
+    assert_eq!(declaration(r"--font-quote: 'it\'s;';x"), Ok(("x", "font-quote")));
+}
+
+#[test]
+fn find_theme_outside_comments_and_strings() {
+    assert_eq!(find_theme("/* @theme { } */ @theme {}"), Some(17));
+    assert_eq!(find_theme(r#".a { content: "@theme"; } @theme {}"#), Some(26));
+    assert_eq!(find_theme("/* @theme {}"), None);
+    assert_eq!(find_theme(".a {}"), None);
+}
+
+#[test]
+fn parse_theme_body() {
+    let body = "
+        /* brand */
+        --color-brand: #fff;
+        @keyframes wiggle {
+            0%, 100% { transform: rotate(-3deg); }
+        }
+        --shadow-elevated: 0 1px 2px black
+    ";
+    assert_eq!(theme_body(body), Ok(("", vec![Some("color-brand"), None, Some("shadow-elevated")])));
+}
@@ -0,0 +1,225 @@
+use std::path::Path;
+
+use nom::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::{escaped, is_not, tag, take_while1};
+use nom::character::complete::{anychar, char, multispace0};
+use nom::combinator::{cut, map, opt, value};
+use nom::multi::separated_list0;
+use nom::sequence::{delimited, preceded, separated_pair, terminated};
+
+use super::{Theme, ThemeError};
+use crate::merge::{MergeOptions, TwMerger};
+
+/// The parts of a Tailwind v3 `tailwind.config.js` that matter for merging.
+///
+/// Load it from a JSON dump of the resolved config, e.g.
+/// `node -e "console.log(JSON.stringify(require('tailwindcss/resolveConfig')(require('./tailwind.config.js'))))"`.
+///
+/// ```
+/// use tw_merge::merge::*;
+///
+/// let config = TailwindConfig::from_json(r#"{
+///     "prefix": "tw-",
+///     "theme": {
+///         "extend": { "fontSize": { "display": ["4rem", { "lineHeight": "1.1" }] } }
+///     }
+/// }"#)
+/// .unwrap();
+///
+/// let merger = config.into_merger();
+/// assert_eq!(merger.merge(&["tw-text-lg tw-text-display"]), "tw-text-display");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TailwindConfig {
+    /// `prefix`, empty if not set.
+    pub prefix: String,
+    /// `separator`, `:` if not set.
+    pub separator: String,
+    /// Custom values of `theme` and `theme.extend`.
+    pub theme: Theme,
+}
+
+impl TailwindConfig {
+    /// Load a JSON dump of a Tailwind v3 config.
+    pub fn from_json(json: &str) -> Result<Self, ThemeError> {
+        let root = match terminated(json_value, multispace0)(json) {
+            Ok(("", root)) => root,
+            Ok((rest, _)) => return Err(syntax_error(json, rest)),
+            Err(nom::Err::Error(error) | nom::Err::Failure(error)) => return Err(syntax_error(json, error.input)),
+            Err(nom::Err::Incomplete(_)) => return Err(syntax_error(json, "")),
+        };
+        let Json::Object(root) = root else {
+            return Err(ThemeError::NotFound);
+        };
+
+        let mut config = Self { prefix: String::new(), separator: ":".to_string(), theme: Theme::default() };
+        for (key, value) in &root {
+            match (*key, value) {
+                ("prefix", Json::String(prefix)) => config.prefix = prefix.to_string(),
+                ("separator", Json::String(separator)) => config.separator = separator.to_string(),
+                ("theme", Json::Object(theme)) => {
+                    config.theme.add_scales(theme);
+                    if let Some((_, Json::Object(extend))) = theme.iter().find(|(key, _)| *key == "extend") {
+                        config.theme.add_scales(extend);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(config)
+    }
+
+    /// Load a JSON dump of a Tailwind v3 config, see [`TailwindConfig::from_json`].
+    pub fn from_json_file(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Create a merger matching the config.
+    ///
+    /// [`MergeOptions`] only holds `&'static str`, so a custom prefix or separator is leaked.
+    /// Load the config once, e.g. at startup.
+    pub fn into_merger(self) -> TwMerger {
+        let Self { prefix, separator, theme } = self;
+        let leak = |value: String, default: &'static str| match value == default {
+            true => default,
+            false => &*Box::leak(value.into_boxed_str()),
+        };
+        let options = MergeOptions { prefix: leak(prefix, ""), separator: leak(separator, ":") };
+        TwMerger::new(options).with_theme(theme)
+    }
+}
+
+impl Theme {
+    // fontSize, colors, etc. of `theme` or `theme.extend`
+    fn add_scales(&mut self, scales: &[(&str, Json)]) {
+        for (key, value) in scales {
+            let Json::Object(values) = value else { continue };
+            let scale = match *key {
+                "fontSize" => &mut self.font_size,
+                "boxShadow" => &mut self.shadow,
+                "dropShadow" => &mut self.drop_shadow,
+                "borderRadius" => &mut self.radius,
+                "spacing" => &mut self.spacing,
+                "screens" => &mut self.breakpoints,
+                "maxWidth" => &mut self.containers,
+                "colors" => {
+                    add_colors(&mut self.colors, "", values);
+                    continue;
+                }
+                _ => continue,
+            };
+            // DEFAULT is the utility without a value (e.g. `rounded`)
+            scale.extend(values.iter().filter(|(key, _)| *key != "DEFAULT").map(|(key, _)| key.to_string()));
+        }
+    }
+}
+
+// { "brand": { "DEFAULT": "..", "500": ".." } } => brand, brand-500
+fn add_colors(colors: &mut std::collections::HashSet<String>, parent: &str, values: &[(&str, Json)]) {
+    for (key, value) in values {
+        let name = match (parent, *key) {
+            (parent, "DEFAULT") => parent.to_string(),
+            ("", key) => key.to_string(),
+            (parent, key) => format!("{parent}-{key}"),
+        };
+        match value {
+            Json::Object(shades) => add_colors(colors, &name, shades),
+            _ if !name.is_empty() => {
+                colors.insert(name);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn syntax_error(json: &str, at: &str) -> ThemeError {
+    let offset = match at.is_empty() {
+        true => json.len(),
+        false => at.as_ptr() as usize - json.as_ptr() as usize,
+    };
+    ThemeError::Syntax { offset, message: "invalid JSON" }
+}
+
+// Only what is needed to read a config, strings are not unescaped.
+#[derive(Clone, Debug, PartialEq)]
+enum Json<'a> {
+    Object(Vec<(&'a str, Json<'a>)>),
+    Array(Vec<Json<'a>>),
+    String(&'a str),
+    // Numbers, booleans and null
+    Scalar,
+}
+
+fn json_value(input: &str) -> IResult<&str, Json<'_>> {
+    preceded(
+        multispace0,
+        alt((
+            map(object, Json::Object),
+            map(array, Json::Array),
+            map(string, Json::String),
+            value(
+                Json::Scalar,
+                alt((
+                    tag("true"),
+                    tag("false"),
+                    tag("null"),
+                    take_while1(|c: char| c.is_ascii_digit() || "+-.eE".contains(c)),
+                )),
+            ),
+        )),
+    )(input)
+}
+
+fn object(input: &str) -> IResult<&str, Vec<(&str, Json<'_>)>> {
+    let entry = separated_pair(preceded(multispace0, string), preceded(multispace0, char(':')), json_value);
+    // Past the opening bracket, report the error where it happened.
+    preceded(
+        char('{'),
+        cut(terminated(separated_list0(preceded(multispace0, char(',')), entry), preceded(multispace0, char('}')))),
+    )(input)
+}
+
+fn array(input: &str) -> IResult<&str, Vec<Json<'_>>> {
+    preceded(
+        char('['),
+        cut(terminated(
+            separated_list0(preceded(multispace0, char(',')), json_value),
+            preceded(multispace0, char(']')),
+        )),
+    )(input)
+}
+
+fn string(input: &str) -> IResult<&str, &str> {
+    map(delimited(char('"'), opt(escaped(is_not("\"\\"), '\\', anychar)), char('"')), Option::unwrap_or_default)(input)
+}
+
+/* ========================================================== */
+/*                       🧪 TESTS 🧪                          */
+/* ========================================================== */
+
+#[test]
+fn parse_json() {
+    let (rest, json) = json_value(r#" { "a": [1, -2.5e3, true, null], "b\"c": "", "d": {} } "#).unwrap();
+    assert_eq!(rest, " ");
+    assert_eq!(
+        json,
+        Json::Object(vec![
+            ("a", Json::Array(vec![Json::Scalar, Json::Scalar, Json::Scalar, Json::Scalar])),
+            ("b\\\"c", Json::String("")),
+            ("d", Json::Object(vec![])),
+        ])
+    );
+}
+
+#[test]
+fn nested_colors() {
+    let mut colors = Default::default();
+    let (_, Json::Object(values)) =
+        json_value(r##"{"brand": {"DEFAULT": "#000", "500": "#111"}, "white": "#fff"}"##).unwrap()
+    else {
+        unreachable!()
+    };
+    add_colors(&mut colors, "", &values);
+    assert_eq!(colors, ["brand", "brand-500", "white"].map(String::from).into());
+}
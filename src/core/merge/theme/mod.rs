@@ -0,0 +1,267 @@
+mod css;
+mod json;
+
+use std::collections::HashSet;
+use std::{fmt, io};
+
+use super::{CollisionIdFn, CollisionTable};
+
+pub use json::TailwindConfig;
+
+/// Custom values of a Tailwind theme.
+///
+/// The default rules only know the default theme, e.g. `text-display` would be a text color.
+/// Listing `display` as a font size makes it collide with `text-lg` instead.
+///
+/// Values are the part of the class after the utility, without the `--namespace-` prefix of the
+/// CSS variable (`--text-display` is `display`, `--color-brand-500` is `brand-500`).
+///
+/// ```
+/// use tw_merge::merge::*;
+///
+/// let theme = Theme::default().with_font_size(["display"]).with_shadow(["elevated"]).with_radius(["pill"]);
+/// let merger = TwMerger::default().with_theme(theme);
+///
+/// assert_eq!(merger.merge(&["text-lg text-red-500 text-display"]), "text-red-500 text-display");
+/// assert_eq!(merger.merge(&["shadow-lg shadow-red-500 shadow-elevated"]), "shadow-red-500 shadow-elevated");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Theme {
+    /// `text-{value}`
+    pub font_size: HashSet<String>,
+    /// `shadow-{value}`
+    pub shadow: HashSet<String>,
+    /// `inset-shadow-{value}`
+    pub inset_shadow: HashSet<String>,
+    /// `drop-shadow-{value}`
+    pub drop_shadow: HashSet<String>,
+    /// `text-shadow-{value}`
+    pub text_shadow: HashSet<String>,
+    /// `rounded-{value}`, `rounded-t-{value}`, etc.
+    pub radius: HashSet<String>,
+    /// `p-{value}`, `m-{value}`, `inset-{value}`, `w-{value}`, etc.
+    pub spacing: HashSet<String>,
+    /// `bg-{value}`, `text-{value}`, `border-{value}`, etc.
+    pub colors: HashSet<String>,
+    /// `max-w-screen-{value}`
+    pub breakpoints: HashSet<String>,
+    /// `max-w-{value}`, `min-w-{value}` and `w-{value}`
+    pub containers: HashSet<String>,
+}
+
+impl Theme {
+    /// Add font sizes, `--text-*` in v4 and `fontSize` in v3.
+    pub fn with_font_size(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.font_size.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add box shadows, `--shadow-*` in v4 and `boxShadow` in v3.
+    pub fn with_shadow(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.shadow.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add inset shadows, `--inset-shadow-*` in v4.
+    pub fn with_inset_shadow(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inset_shadow.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add drop shadows, `--drop-shadow-*` in v4 and `dropShadow` in v3.
+    pub fn with_drop_shadow(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.drop_shadow.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add text shadows, `--text-shadow-*` in v4.
+    pub fn with_text_shadow(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.text_shadow.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add border radiuses, `--radius-*` in v4 and `borderRadius` in v3.
+    pub fn with_radius(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.radius.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add spacing values, `--spacing-*` in v4 and `spacing` in v3.
+    pub fn with_spacing(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.spacing.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add colors, `--color-*` in v4 and `colors` in v3.
+    pub fn with_colors(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.colors.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add breakpoints, `--breakpoint-*` in v4 and `screens` in v3.
+    pub fn with_breakpoints(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.breakpoints.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add container sizes, `--container-*` in v4 and `maxWidth` in v3.
+    pub fn with_containers(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.containers.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns `true` if the theme has no custom values.
+    pub fn is_empty(&self) -> bool {
+        let Self {
+            font_size,
+            shadow,
+            inset_shadow,
+            drop_shadow,
+            text_shadow,
+            radius,
+            spacing,
+            colors,
+            breakpoints,
+            containers,
+        } = self;
+        [font_size, shadow, inset_shadow, drop_shadow, text_shadow, radius, spacing, colors, breakpoints, containers]
+            .iter()
+            .all(|scale| scale.is_empty())
+    }
+
+    /// The CollisionId of a class using a custom theme value, `None` if it doesn't use one.
+    pub fn collision_id(&self, elements: &[&str], arbitrary: Option<&str>) -> Option<&'static str> {
+        self.collision_id_in(CollisionTable::default_ref(), elements, arbitrary)
+    }
+
+    // Placeholders are resolved with the table of the merger.
+    pub(crate) fn collision_id_in(
+        &self,
+        table: &CollisionTable,
+        elements: &[&str],
+        arbitrary: Option<&str>,
+    ) -> Option<&'static str> {
+        if arbitrary.is_some() || self.is_empty() {
+            return None;
+        }
+
+        // text-shadow-elevated: try `text` with `shadow-elevated`, then `text-shadow` with `elevated`
+        (1..elements.len()).find_map(|split| {
+            let (utility, value) = elements.split_at(split);
+            let placeholder = self.placeholder(utility, &value.join("-"))?;
+            // Resolve it like a value from the default theme.
+            let elements: Vec<&str> = utility.iter().chain(placeholder).copied().collect();
+            table.lookup(&elements, None)
+        })
+    }
+
+    // A value of the default theme from the same scale.
+    fn placeholder(&self, utility: &[&str], value: &str) -> Option<&'static [&'static str]> {
+        match utility {
+            ["text"] if self.font_size.contains(value) => Some(&["base"]),
+            ["shadow"] if self.shadow.contains(value) => Some(&["sm"]),
+            ["inset", "shadow"] if self.inset_shadow.contains(value) => Some(&["sm"]),
+            ["drop", "shadow"] if self.drop_shadow.contains(value) => Some(&["sm"]),
+            ["text", "shadow"] if self.text_shadow.contains(value) => Some(&["sm"]),
+            ["rounded", ..] if self.radius.contains(value) => Some(&["lg"]),
+            [first, ..] if is_spacing_utility(first) && self.spacing.contains(value) => Some(&["1"]),
+            _ if self.colors.contains(value) => Some(&["red", "500"]),
+            ["min" | "max", "w"]
+                if value.strip_prefix("screen-").is_some_and(|breakpoint| self.breakpoints.contains(breakpoint)) =>
+            {
+                Some(&["screen", "sm"])
+            }
+            ["w"] | ["min" | "max", "w"] if self.containers.contains(value) => Some(&["md"]),
+            _ => None,
+        }
+    }
+}
+
+impl CollisionIdFn for Theme {
+    fn apply(&self, elements: &[&str], arbitrary: Option<&str>) -> Option<&'static str> {
+        self.collision_id(elements, arbitrary)
+    }
+}
+
+fn is_spacing_utility(first: &str) -> bool {
+    matches!(
+        first,
+        "p" | "px"
+            | "py"
+            | "pt"
+            | "pr"
+            | "pb"
+            | "pl"
+            | "ps"
+            | "pe"
+            | "m"
+            | "mx"
+            | "my"
+            | "mt"
+            | "mr"
+            | "mb"
+            | "ml"
+            | "ms"
+            | "me"
+            | "gap"
+            | "space"
+            | "w"
+            | "h"
+            | "min"
+            | "max"
+            | "size"
+            | "basis"
+            | "inset"
+            | "top"
+            | "right"
+            | "bottom"
+            | "left"
+            | "start"
+            | "end"
+            | "translate"
+            | "scroll"
+            | "indent"
+    )
+}
+
+/// Error while loading a [`Theme`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ThemeError {
+    /// The theme file could not be read.
+    Io(io::Error),
+    /// The theme could not be parsed.
+    Syntax {
+        /// Byte offset of the error within the input.
+        offset: usize,
+        /// What was expected at `offset`.
+        message: &'static str,
+    },
+    /// The input does not define a theme (e.g. no `@theme` block).
+    NotFound,
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "Could not read theme: {error}"),
+            Self::Syntax { offset, message } => write!(f, "Invalid theme at byte {offset}: {message}"),
+            Self::NotFound => write!(f, "No theme found"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ThemeError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
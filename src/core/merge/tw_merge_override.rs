@@ -1,8 +1,12 @@
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Range;
 
-use super::{CollisionIdFn, GetCollisionsFn, MergeOptions};
+use super::collision_table::{INVALID_SYNTAX, Rejected};
+use super::{CollisionIdFn, CollisionTable, GetCollisionsFn, MergeOptions};
 use crate::ast::AstStyle;
-use crate::core::merge::get_collisions::get_collisions;
+use crate::core::merge::get_collisions::get_modifier_collisions;
+use crate::core::merge::sort_variants::sort_variants_into;
 
 /// Merges all the Tailwind classes, resolving conflicts.
 /// Can supply custom options, collision_id_fn and collisions_fn.
@@ -12,95 +16,237 @@ pub fn tw_merge_override(
     collision_id_fn: impl CollisionIdFn,
     collisions_fn: impl GetCollisionsFn,
 ) -> String {
-    let styles: Vec<Result<AstStyle, &str>> = crate::ast::parse_tailwind(class, options.into());
+    let mut merged = String::new();
+    merge_into_override(class, options, CollisionTable::default_ref(), &collision_id_fn, &collisions_fn, &mut merged);
+    merged
+}
+
+/// Merges into `out`, replacing its content but keeping its allocation.
+pub(crate) fn merge_into_override(
+    class: &[&str],
+    options: MergeOptions,
+    table: &CollisionTable,
+    collision_id_fn: &impl CollisionIdFn,
+    collisions_fn: &impl GetCollisionsFn,
+    out: &mut String,
+) {
+    let resolved = resolve(class, options, table, collision_id_fn, collisions_fn);
+    out.clear();
+    push_kept(&resolved, out);
+}
+
+/// Borrows the input if the merge doesn't change it: no class is dropped and classes are separated by one space.
+pub(crate) fn merge_cow_override<'a>(
+    class: &'a str,
+    options: MergeOptions,
+    table: &CollisionTable,
+    collision_id_fn: &impl CollisionIdFn,
+    collisions_fn: &impl GetCollisionsFn,
+) -> Cow<'a, str> {
+    let resolved = resolve(&[class], options, table, collision_id_fn, collisions_fn);
+    let unchanged = resolved.iter().all(|resolved| resolved.dropped_by.is_none())
+        && (class.is_empty() || class.split(' ').eq(resolved.iter().map(|resolved| resolved.source)));
+    if unchanged {
+        return Cow::Borrowed(class);
+    }
+    let mut merged = String::with_capacity(class.len());
+    push_kept(&resolved, &mut merged);
+    Cow::Owned(merged)
+}
 
-    let mut valid_styles: Vec<Result<AstStyle, &str>> = vec![];
-    let mut collision_styles: HashSet<Collision> = HashSet::new();
+fn push_kept(resolved: &[Resolved], out: &mut String) {
+    let kept = resolved.iter().filter(|resolved| resolved.dropped_by.is_none());
+    for (index, resolved) in kept.enumerate() {
+        if index > 0 {
+            out.push(' ');
+        }
+        out.push_str(resolved.source);
+    }
+}
 
-    for style in styles.into_iter().rev() {
+/// What happened to a single class, in input order.
+pub(crate) struct Resolved<'a> {
+    pub source: &'a str,
+    /// Byte range of the class in the input strings joined by a space.
+    pub span: Range<usize>,
+    /// `None` if the class could not be parsed.
+    pub style: Option<AstStyle<'a>>,
+    /// `None` if the class is not a known Tailwind class.
+    pub collision_id: Option<&'a str>,
+    /// Why the class is not a known Tailwind class, from parsing or [`CollisionTable::class_id`].
+    pub rejected: Option<Rejected>,
+    /// Index and CollisionId of the later class that knocked this one out.
+    pub dropped_by: Option<(usize, &'a str)>,
+}
+
+/// Resolves conflicts, right most class takes precedence.
+pub(crate) fn resolve<'a>(
+    class: &[&'a str],
+    options: MergeOptions,
+    table: &CollisionTable,
+    collision_id_fn: &impl CollisionIdFn,
+    collisions_fn: &impl GetCollisionsFn,
+) -> Vec<Resolved<'a>> {
+    let options = options.into();
+    let styles: Vec<(Range<usize>, Result<AstStyle, &str>)> = crate::ast::split_classes(class)
+        .map(|(start, class)| (start..start + class.len(), crate::ast::parse_single(class, &options).ok_or(class)))
+        .collect();
+
+    let mut resolved: Vec<Resolved> = Vec::with_capacity(styles.len());
+    let mut keys = Keys::new(table);
+    // The value is the class that added the collision, and its own CollisionId.
+    let mut collision_styles: HashMap<Collision, (usize, &str)> = HashMap::with_capacity(styles.len());
+    // The arbitrary value of a CSS variable, reused across classes.
+    let mut css_variable = String::new();
+
+    for (index, (span, style)) in styles.into_iter().enumerate().rev() {
         let style = match style {
             Ok(style) => style,
-            Err(s) => {
-                valid_styles.push(Err(s));
+            Err(source) => {
+                let rejected = Some(Rejected { error: INVALID_SYNTAX, group: None });
+                resolved.push(Resolved { source, span, style: None, collision_id: None, rejected, dropped_by: None });
                 continue;
             }
         };
 
-        let elements = style.elements.as_slice();
-        let result = collision_id_fn.apply(elements, style.arbitrary).map(Ok).unwrap_or_else(|| {
-            let arbitrary = style.arbitrary.unwrap_or_default();
-            super::get_collision_id::get_collision_id(elements, arbitrary)
-        });
-
-        match result {
-            Err(error) => match Collision::check_arbitrary(style.clone()) {
-                Some(collision) => {
-                    if collision_styles.contains(&collision) {
-                        continue;
-                    }
-                    collision_styles.insert(collision);
+        // bg-(--brand) collides exactly like bg-[var(--brand)]
+        let arbitrary = match (style.arbitrary, style.css_variable) {
+            (None, Some(variable)) => {
+                variable.write_arbitrary(&mut css_variable);
+                Some(css_variable.as_str())
+            }
+            (arbitrary, _) => arbitrary,
+        };
+        let mut result = table.class_id(&style, arbitrary, collision_id_fn);
+        // [color:red] collides like text-red-500
+        if result.is_err()
+            && let Some(collision_id) = arbitrary_property(&style).and_then(|name| table.property(name))
+        {
+            result = Ok(collision_id);
+        }
+
+        let (collision_id, rejected, dropped_by) = match result {
+            Err(error) => match unknown_property(&style) {
+                // [paint-order:normal] only collides with the same property
+                Some(collision_id) => {
+                    let collision = Collision {
+                        important: style.important,
+                        variants: keys.variants(&style.variants),
+                        collision_id: keys.id(collision_id),
+                    };
+                    let dropped_by = collision_styles.get(&collision).copied();
+                    collision_styles.entry(collision).or_insert((index, collision_id));
+                    (Some(collision_id), None, dropped_by)
                 }
                 None => {
                     #[cfg(feature = "debug")]
                     println!("No Instance found: {style:?} {error:?}");
-                    let _ = error;
+                    (None, Some(error), None)
                 }
             },
             Ok(collision_id) => {
                 // hover:md:focus
-                let all_variants: Vec<&str> = style.variants.clone();
+                let variants = keys.variants(&style.variants);
+                let key = keys.id(collision_id);
+                let collision = Collision { important: style.important, variants, collision_id: key };
 
-                let collision = Collision { important: style.important, variants: all_variants.clone(), collision_id };
-
-                if collision_styles.contains(&collision) {
+                if let Some(&dropped_by) = collision_styles.get(&collision) {
+                    resolved.push(Resolved {
+                        source: style.source,
+                        span,
+                        style: Some(style),
+                        collision_id: Some(collision_id),
+                        rejected: None,
+                        dropped_by: Some(dropped_by),
+                    });
                     continue;
                 }
 
                 // Add the current collision_id.
-                collision_styles.insert(collision);
+                collision_styles.insert(collision, (index, collision_id));
 
-                let collisions = collisions_fn.apply(collision_id).or_else(|| get_collisions(collision_id));
-
-                if let Some(collisions) = collisions {
-                    collisions.into_iter().for_each(|collision_id| {
-                        let collision =
-                            Collision { important: style.important, variants: all_variants.clone(), collision_id };
+                let mut knock_out = |other: u32| {
+                    let collision = Collision { important: style.important, variants, collision_id: other };
+                    collision_styles.entry(collision).or_insert((index, collision_id));
+                };
+                match collisions_fn.apply(collision_id) {
+                    Some(collisions) => collisions.iter().for_each(|other_id| knock_out(keys.id(other_id))),
+                    None => table.collision_keys(key).iter().for_each(|&other| knock_out(other)),
+                }
 
-                        collision_styles.insert(collision);
-                    });
+                // text-lg/7 also sets the line-height
+                if style.modifier.is_some() {
+                    get_modifier_collisions(collision_id).iter().for_each(|other_id| knock_out(keys.id(other_id)));
                 }
+
+                (Some(collision_id), None, None)
             }
-        }
-        valid_styles.push(Ok(style));
+        };
+        resolved.push(Resolved { source: style.source, span, style: Some(style), collision_id, rejected, dropped_by });
     }
 
-    valid_styles.reverse();
-
-    valid_styles
-        .into_iter()
-        .map(|s| match s {
-            Ok(style) => style.source,
-            Err(s) => s,
-        })
-        .collect::<Vec<_>>()
-        .join(" ")
+    resolved.reverse();
+    resolved
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct Collision<'a> {
+/// A class knocks out earlier classes with the same key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Collision {
     important: bool,
-    variants: Vec<&'a str>,
-    collision_id: &'a str,
+    /// Key of the sorted variants, from [`Keys::variants`].
+    variants: u32,
+    /// Key of the CollisionId, from [`Keys::id`].
+    collision_id: u32,
+}
+
+/// Compact keys of the CollisionIds and variant chains of a merge, so comparing classes doesn't allocate.
+struct Keys<'a, 't> {
+    table: &'t CollisionTable,
+    // CollisionIds missing from the table, keyed after the ids of the table.
+    ids: HashMap<&'a str, u32>,
+    // Interned sorted variant chains, keyed from 1.
+    chains: HashMap<Vec<&'a str>, u32>,
+    sorted: Vec<&'a str>,
 }
 
-// For [color:blue] => label = "color"
-impl<'a> Collision<'a> {
-    fn check_arbitrary(style: AstStyle<'a>) -> Option<Self> {
-        let arbitrary = style.arbitrary?;
-        let index = arbitrary.find(':')?;
-        let (collision_id, _) = arbitrary.split_at(index);
-        Some(Self { collision_id, important: style.important, variants: style.variants })
+impl<'a, 't> Keys<'a, 't> {
+    fn new(table: &'t CollisionTable) -> Self {
+        Self { table, ids: HashMap::new(), chains: HashMap::new(), sorted: Vec::new() }
+    }
+
+    fn id(&mut self, id: &'a str) -> u32 {
+        if let Some(key) = self.table.key(id) {
+            return key;
+        }
+        let key = self.table.key_count() + self.ids.len() as u32;
+        *self.ids.entry(id).or_insert(key)
     }
+
+    /// `0` for no variants.
+    fn variants(&mut self, variants: &[&'a str]) -> u32 {
+        if variants.is_empty() {
+            return 0;
+        }
+        sort_variants_into(variants, &mut self.sorted);
+        // Only a new chain allocates.
+        if let Some(&key) = self.chains.get(self.sorted.as_slice()) {
+            return key;
+        }
+        let key = self.chains.len() as u32 + 1;
+        self.chains.insert(self.sorted.clone(), key);
+        key
+    }
+}
+
+// For [paint-order:normal] => "paint-order", for a class that is not an arbitrary property => None
+fn unknown_property<'a>(style: &AstStyle<'a>) -> Option<&'a str> {
+    let (property, _) = style.arbitrary?.split_once(':')?;
+    Some(property)
+}
+
+// The CSS property of an arbitrary property, `color` for [color:blue]
+fn arbitrary_property<'a>(style: &AstStyle<'a>) -> Option<&'a str> {
+    unknown_property(style).filter(|_| style.elements.is_empty())
 }
 
 /* ========================================================== */
@@ -108,11 +254,27 @@ impl<'a> Collision<'a> {
 /* ========================================================== */
 
 #[test]
-fn check_arbitrary() {
-    let style = crate::ast::parse_tailwind(&["[color:blue]"], Default::default()).into_iter().next().unwrap().unwrap();
+fn arbitrary_properties() {
+    let styles = crate::ast::parse_tailwind(&["[color:blue] m-[2px] [--my-var:calc(1px+2px)]"], Default::default());
+    let styles: Vec<_> = styles.into_iter().map(Result::unwrap).collect();
+    let properties: Vec<_> = styles.iter().map(arbitrary_property).collect();
+    assert_eq!(properties, [Some("color"), None, Some("--my-var")]);
+    assert_eq!(unknown_property(&styles[1]), None);
+}
+
+#[test]
+fn interned_keys() {
+    let table = CollisionTable::default_ref();
+    let mut keys = Keys::new(table);
+    assert_eq!(keys.id("padding"), table.key("padding").unwrap());
+    assert_eq!(keys.id("paint-order"), table.key_count());
+    assert_eq!(keys.id("mask-mode"), table.key_count() + 1);
+    assert_eq!(keys.id("paint-order"), table.key_count());
 
-    assert_eq!(
-        Collision::check_arbitrary(style),
-        Some(Collision { important: false, variants: vec![], collision_id: "color" })
-    );
+    assert_eq!(keys.variants(&[]), 0);
+    assert_eq!(keys.variants(&["md", "hover"]), 1);
+    assert_eq!(keys.variants(&["hover", "md"]), 1);
+    assert_eq!(keys.variants(&["hover"]), 2);
+    assert_eq!(keys.variants(&["[&>*]", "hover"]), 3);
+    assert_eq!(keys.variants(&["hover", "[&>*]"]), 4);
 }
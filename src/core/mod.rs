@@ -1,9 +1,13 @@
+/// Variant values per breakpoint.
+mod responsive;
 /// Joins the given classes into a single string.
 mod tw_join;
 
 /// Merges all the Tailwind classes, resolving conflicts.
 pub mod merge;
 
+pub use responsive::*;
+
 /* ========================================================== */
 /*                       🏹 TRAITS 🏹                         */
 /* ========================================================== */
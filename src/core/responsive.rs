@@ -0,0 +1,140 @@
+use std::sync::OnceLock;
+
+use crate::AsTailwindClass;
+use crate::merge::{MergeOptions, TwMerger, tw_merge_slice};
+
+/// A variant value per breakpoint, like `{ base: Sm, md: Lg }`.
+///
+/// The classes of each breakpoint value are prefixed with the breakpoint and the separator of the
+/// [`crate::merge::TwMerger`] in scope, or of the global [`MergeOptions`], then merged after the base value.
+/// Classes keep their own Tailwind prefix, `tw-px-8` becomes `md:tw-px-8`.
+///
+/// Nothing is merged until the classes are needed. [`Responsive::to_class`] merges on every call,
+/// while [`AsTailwindClass::as_class`] keeps the result for each [`MergeOptions`] it was called with.
+///
+/// ```
+/// use tw_merge::*;
+///
+/// enum Size {
+///     Sm,
+///     Lg,
+/// }
+///
+/// impl AsTailwindClass for Size {
+///     fn as_class(&self) -> &str {
+///         match self {
+///             Size::Sm => "h-8 px-3",
+///             Size::Lg => "h-10 px-8",
+///         }
+///     }
+/// }
+///
+/// let size = Responsive::new(Size::Sm).at("md", Size::Lg);
+/// assert_eq!(size.as_class(), "h-8 px-3 md:h-10 md:px-8");
+/// assert_eq!(tw_merge!(size, "md:px-4"), "h-8 px-3 md:h-10 md:px-4");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Responsive<T> {
+    base: T,
+    breakpoints: Vec<(&'static str, T)>,
+    // Merged by `as_class`, per MergeOptions in scope.
+    merged: Merged,
+}
+
+// A list, as `as_class` can't replace a result it has lent out.
+#[derive(Clone, Debug, Default)]
+struct Merged {
+    class: OnceLock<(MergeOptions, String)>,
+    next: OnceLock<Box<Merged>>,
+}
+
+impl Merged {
+    fn get_or_merge(&self, options: MergeOptions, merge: impl FnOnce() -> String) -> &str {
+        let mut merged = self;
+        let mut merge = Some(merge);
+        loop {
+            let (class_options, class) = merged.class.get_or_init(|| (options, merge.take().unwrap()()));
+            if *class_options == options {
+                return class;
+            }
+            merged = merged.next.get_or_init(Default::default);
+        }
+    }
+}
+
+impl<T: AsTailwindClass> Responsive<T> {
+    /// The value below the first breakpoint.
+    pub fn new(base: T) -> Self {
+        Self { base, breakpoints: Vec::new(), merged: Merged::default() }
+    }
+
+    /// Use `value` from `breakpoint` up, e.g. `md` or `max-lg`.
+    ///
+    /// Later breakpoints take precedence when their classes conflict.
+    pub fn at(mut self, breakpoint: &'static str, value: T) -> Self {
+        self.breakpoints.push((breakpoint, value));
+        self.merged = Merged::default();
+        self
+    }
+
+    /// The value below the first breakpoint.
+    pub fn base(&self) -> &T {
+        &self.base
+    }
+
+    /// The values of each breakpoint, in the order they were added.
+    pub fn breakpoints(&self) -> &[(&'static str, T)] {
+        &self.breakpoints
+    }
+
+    /// Merges the classes of every breakpoint, with the [`TwMerger`] in scope.
+    pub fn to_class(&self) -> String {
+        self.merge(TwMerger::options_scoped().unwrap_or_default())
+    }
+
+    fn merge(&self, MergeOptions { separator, .. }: MergeOptions) -> String {
+        let breakpoints = self.breakpoints.iter().flat_map(|(breakpoint, value)| {
+            value.as_class().split_whitespace().map(move |class| format!("{breakpoint}{separator}{class}"))
+        });
+        let classes: Vec<String> = std::iter::once(self.base.as_class().to_string()).chain(breakpoints).collect();
+        tw_merge_slice(&classes.iter().map(String::as_str).collect::<Vec<_>>())
+    }
+}
+
+impl<T: AsTailwindClass> From<T> for Responsive<T> {
+    fn from(base: T) -> Self {
+        Self::new(base)
+    }
+}
+
+impl<T: AsTailwindClass + Default> Default for Responsive<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: PartialEq> PartialEq for Responsive<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base && self.breakpoints == other.breakpoints
+    }
+}
+
+impl<T: Eq> Eq for Responsive<T> {}
+
+impl<T: AsTailwindClass> AsTailwindClass for Responsive<T> {
+    fn as_class(&self) -> &str {
+        let options = TwMerger::options_scoped().unwrap_or_default();
+        self.merged.get_or_merge(options, || self.merge(options))
+    }
+}
+
+#[cfg(feature = "variant")]
+impl<T: AsTailwindClass> crate::IntoTailwindClass for Responsive<T> {
+    fn to_class(&self) -> String {
+        Responsive::to_class(self)
+    }
+
+    fn with_class(&self, class: impl AsRef<str>) -> String {
+        tw_merge_slice(&[&Responsive::to_class(self), class.as_ref()])
+    }
+}
@@ -94,6 +94,52 @@
 //!
 //! ```
 //!
+//! If you need more than one configuration, create a [`merge::TwMerger`] instead.
+//! It can be passed to [`tw_merge!`] directly, or scoped to the current thread.
+//!
+//! ```
+//! use tw_merge::{*, merge::*};
+//!
+//! let merger = TwMerger::new(MergeOptions { prefix: "ds-", separator: ":" });
+//!
+//! assert_eq!("ds-p-4", tw_merge!(merger => "ds-p-2", "ds-p-4"));
+//!
+//! let _guard = merger.scope();
+//! assert_eq!("ds-p-4", tw_merge!("ds-p-2", "ds-p-4"));
+//! ```
+//!
+//! With the `cache` feature, merged strings are memoized in a bounded LRU cache, which pays off when the same
+//! classes are merged on every render. [`tw_merge!`] uses [`merge::MergeCache::global`], or create your own
+//! [`merge::MergeCache`].
+//!
+//! ```bash
+//! cargo add tw_merge --features cache
+//! ```
+//!
+//! With the `cli` feature, the `tw-merge` binary merges, explains or lints one class list per line of stdin,
+//! which is handy in pre-commit hooks.
+//!
+//! ```bash
+//! cargo install tw_merge --features cli
+//! echo "p-2 felx p-4" | tw-merge lint --config app.css
+//! ```
+//!
+//! Tailwind doesn't understand `#[tw(class = "...")]` attributes, so `tw-merge scan` extracts the classes of the
+//! `.rs` files, from `#[tw(...)]` attributes and [`tw_merge!`]-like macros, into a safelist for Tailwind to read.
+//! The same extractor is available as [`scan::scan_paths`].
+//!
+//! ```bash
+//! tw-merge scan src --output safelist.txt
+//! # app.css: @source "./safelist.txt";
+//! ```
+//!
+//! [`merge::sort_classes`] orders classes like prettier-plugin-tailwindcss, and `tw-merge sort` rewrites the class strings of
+//! the `.rs` files in place. Strings where the order decides a conflict are left as they are.
+//!
+//! ```bash
+//! tw-merge sort src --check  # in CI
+//! ```
+//!
 //!
 //! ## Usage: Variants
 //!
@@ -240,6 +286,19 @@
 //!
 //! ```
 //!
+//! ### Responsive Values
+//!
+//! [`Responsive`] holds a variant value per breakpoint. The classes of each breakpoint are prefixed with it, using the
+//! configured separator, and merged after the base value.
+//! The classes are merged when they are rendered, with the [`merge::TwMerger`] in scope at that time.
+//! It implements [`AsTailwindClass`] and, with the `variant` feature, `IntoTailwindClass`.
+//!
+//! ```ignore
+//! let size = Responsive::new(BtnSize::Sm).at("md", BtnSize::Lg);
+//!
+//! assert_eq!(size.as_class(), "h-8 px-3 md:h-10 md:px-8");
+//! ```
+//!
 //! #### VSCode Intellisense
 //!
 //! You can enable autocompletion inside `#[tw()]` using the steps below:
@@ -259,8 +318,11 @@
 
 pub use crate::core::{merge, *};
 
-mod ast;
+/// Parses Tailwind classes exactly the way the merger sees them.
+pub mod ast;
 mod core;
+/// Extracts the Tailwind classes used in Rust sources, to feed Tailwind a safelist.
+pub mod scan;
 
 #[cfg(feature = "variant")]
 pub use variant::*;
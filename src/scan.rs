@@ -0,0 +1,421 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use crate::merge::TwMerger;
+
+/// Macros whose string literal arguments are Tailwind classes.
+pub const MACROS: &[&str] = &["tw_merge", "tw_join"];
+
+/// A class found in a Rust source by [`scan_source`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScannedClass<'a> {
+    /// The class as it appears in the string literal.
+    pub class: &'a str,
+    /// 1-based line of the class.
+    pub line: usize,
+    /// 1-based column of the class, in characters.
+    pub column: usize,
+}
+
+/// A class found in a file by [`scan_paths`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClassLocation {
+    /// The class as it appears in the string literal.
+    pub class: String,
+    /// The scanned file.
+    pub path: PathBuf,
+    /// 1-based line of the class.
+    pub line: usize,
+    /// 1-based column of the class, in characters.
+    pub column: usize,
+}
+
+/// Extracts the Tailwind classes of a Rust source.
+///
+/// Classes are taken from the `class = "..."` string literals of `#[tw(...)]` attributes,
+/// and from every string literal passed to one of the [`MACROS`].
+/// Comments and other string literals are ignored.
+///
+/// Classes containing escape sequences other than `\n`, `\t` or a line continuation are skipped,
+/// use a raw string literal for those.
+///
+/// ```
+/// use tw_merge::scan::scan_source;
+///
+/// let source = r#"
+/// #[derive(TwVariant)]
+/// enum Size {
+///     #[tw(default, class = "h-9 px-4")]
+///     Default,
+/// }
+/// let class = tw_merge!("flex", active.then_some("font-bold"));
+/// "#;
+/// let classes: Vec<_> = scan_source(source).iter().map(|found| found.class).collect();
+/// assert_eq!(classes, ["h-9", "px-4", "flex", "font-bold"]);
+/// assert_eq!((scan_source(source)[2].line, scan_source(source)[2].column), (7, 24));
+/// ```
+pub fn scan_source(source: &str) -> Vec<ScannedClass<'_>> {
+    let literals = class_literals(&tokenize(source));
+    let lines = LineIndex::new(source);
+    literals
+        .into_iter()
+        .flat_map(|literal| literal.classes(source))
+        .map(|(offset, class)| {
+            let (line, column) = lines.position(source, offset);
+            ScannedClass { class, line, column }
+        })
+        .collect()
+}
+
+/// Extracts the Tailwind classes of the `.rs` files in the paths, see [`scan_source`].
+///
+/// Directories are walked as in [`rust_files`], files passed directly are scanned whatever their extension.
+pub fn scan_paths(paths: impl IntoIterator<Item = impl AsRef<Path>>) -> io::Result<Vec<ClassLocation>> {
+    let mut found = Vec::new();
+    for path in rust_files(paths)? {
+        let source = fs::read_to_string(&path)?;
+        found.extend(scan_source(&source).into_iter().map(|ScannedClass { class, line, column }| ClassLocation {
+            class: class.to_string(),
+            path: path.clone(),
+            line,
+            column,
+        }));
+    }
+    Ok(found)
+}
+
+/// Deduplicated and sorted classes, one per line.
+///
+/// Tailwind picks up every class of a plain text file,
+/// with `@source "./safelist.txt";` in v4 or `content: ["./safelist.txt"]` in v3.
+///
+/// ```
+/// use tw_merge::scan::safelist;
+///
+/// assert_eq!(safelist(["p-4", "flex", "p-4"]), "flex\np-4\n");
+/// ```
+pub fn safelist<'a>(classes: impl IntoIterator<Item = &'a str>) -> String {
+    let classes: BTreeSet<&str> = classes.into_iter().collect();
+    classes.into_iter().flat_map(|class| [class, "\n"]).collect()
+}
+
+/// Sorts the classes of the string literals read by [`scan_source`] in place, see [`crate::merge::sort_classes`].
+///
+/// A literal is left untouched if it spans several lines, holds escape sequences, or if sorting it would change
+/// the classes the merger keeps, like `"px-2 p-4"` where `p-4` only overrides `px-2` because it comes later.
+/// Whitespace around the classes is kept, classes are separated by single spaces.
+///
+/// ```
+/// use tw_merge::{merge::TwMerger, scan::sort_source};
+///
+/// let source = r#"let class = tw_merge!("text-white p-4 flex ", "px-2 p-4");"#;
+/// let sorted = r#"let class = tw_merge!("flex p-4 text-white ", "px-2 p-4");"#;
+/// assert_eq!(sort_source(source, &TwMerger::default()), sorted);
+/// ```
+pub fn sort_source(source: &str, merger: &TwMerger) -> String {
+    let kept = |class: &str| {
+        let mut kept: Vec<String> = merger.merge(&[class]).split_whitespace().map(String::from).collect();
+        kept.sort_unstable();
+        kept
+    };
+
+    let mut sorted = String::with_capacity(source.len());
+    let mut end = 0;
+    for literal in class_literals(&tokenize(source)) {
+        let content = &source[literal.start..literal.end];
+        if content.contains('\n') || (!literal.raw && content.contains('\\')) {
+            continue;
+        }
+        let classes = content.trim();
+        let sorted_classes = merger.sort_classes(&[classes]);
+        if sorted_classes == classes || kept(classes) != kept(&sorted_classes) {
+            continue;
+        }
+        let start = literal.start + content.len() - content.trim_start().len();
+        sorted.push_str(&source[end..start]);
+        sorted.push_str(&sorted_classes);
+        end = start + classes.len();
+    }
+    sorted.push_str(&source[end..]);
+    sorted
+}
+
+/// The `.rs` files of the directories, and the other paths as they are.
+///
+/// Directories are walked recursively in alphabetical order, skipping `target` and hidden directories.
+pub fn rust_files(paths: impl IntoIterator<Item = impl AsRef<Path>>) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        let path = path.as_ref();
+        match path.is_dir() {
+            true => collect_files(path, &mut files)?,
+            false => files.push(path.to_path_buf()),
+        }
+    }
+    Ok(files)
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries =
+        fs::read_dir(dir)?.map(|entry| entry.map(|entry| entry.path())).collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        if path.is_dir() {
+            if name != "target" && !name.starts_with('.') {
+                collect_files(&path, files)?;
+            }
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+// String literals holding classes: the `#[tw(...)]` attributes and the arguments of the [`MACROS`].
+fn class_literals(tokens: &[Token<'_>]) -> Vec<Literal> {
+    let mut literals = Vec::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        index += match tokens[index..] {
+            // tw_merge!( ... )
+            [Token::Ident(name), Token::Punct(b'!'), Token::Punct(open @ (b'(' | b'[' | b'{')), ..]
+                if MACROS.contains(&name) =>
+            {
+                let body = delimited(&tokens[index + 2..], open);
+                literals.extend(body.iter().filter_map(|token| match token {
+                    Token::Str(literal) => Some(*literal),
+                    _ => None,
+                }));
+                body.len() + 2
+            }
+            _ if let Some((attribute, len)) = tw_attribute(&tokens[index..]) => {
+                literals.extend(attribute);
+                len
+            }
+            _ => 1,
+        };
+    }
+    literals
+}
+
+// `class = "..."` literals of a `#[tw(...)]` attribute at the start of the tokens, and the length of the attribute.
+fn tw_attribute(tokens: &[Token<'_>]) -> Option<(Vec<Literal>, usize)> {
+    let [Token::Punct(b'#'), Token::Punct(b'['), Token::Ident("tw"), Token::Punct(b'('), ..] = tokens else {
+        return None;
+    };
+    let body = delimited(&tokens[3..], b'(');
+    let literals = body
+        .windows(3)
+        .filter_map(|window| match window {
+            [Token::Ident("class"), Token::Punct(b'='), Token::Str(literal)] => Some(*literal),
+            _ => None,
+        })
+        .collect();
+    Some((literals, body.len() + 4))
+}
+
+// Tokens inside the opening delimiter, up to and including the matching closing one.
+fn delimited<'t, 'a>(tokens: &'t [Token<'a>], open: u8) -> &'t [Token<'a>] {
+    let close = match open {
+        b'(' => b')',
+        b'[' => b']',
+        _ => b'}',
+    };
+    let mut depth = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct(punct) if *punct == open => depth += 1,
+            Token::Punct(punct) if *punct == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return &tokens[..=index];
+                }
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Ident(&'a str),
+    Punct(u8),
+    Str(Literal),
+}
+
+// Content of a string literal, as a byte range of the source.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Literal {
+    start: usize,
+    end: usize,
+    raw: bool,
+}
+
+impl Literal {
+    // Classes with their byte offset in the source.
+    fn classes<'a>(&self, source: &'a str) -> Vec<(usize, &'a str)> {
+        let content = &source[self.start..self.end];
+        let mut classes = Vec::new();
+        let mut start = None;
+        let mut escaped = false;
+        let mut chars = content.char_indices().peekable();
+        while let Some((index, char)) = chars.next() {
+            let separator = match char {
+                '\\' if !self.raw => match chars.peek().map(|(_, next)| *next) {
+                    Some('n' | 't' | 'r' | '\n') => {
+                        chars.next();
+                        true
+                    }
+                    _ => {
+                        escaped = true;
+                        chars.next();
+                        false
+                    }
+                },
+                char => char.is_whitespace(),
+            };
+            match (separator, start) {
+                (true, Some(class_start)) => {
+                    if !escaped {
+                        classes.push((self.start + class_start, &content[class_start..index]));
+                    }
+                    (start, escaped) = (None, false);
+                }
+                (false, None) => start = Some(index),
+                _ => {}
+            }
+        }
+        if let Some(class_start) = start
+            && !escaped
+        {
+            classes.push((self.start + class_start, &content[class_start..]));
+        }
+        classes
+    }
+}
+
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let bytes = source.as_bytes();
+    let is_ident = |byte: u8| byte == b'_' || byte.is_ascii_alphanumeric() || !byte.is_ascii();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        let next = bytes.get(index + 1).copied();
+        match byte {
+            _ if byte.is_ascii_whitespace() => index += 1,
+            b'/' if next == Some(b'/') => {
+                index = source[index..].find('\n').map_or(bytes.len(), |end| index + end);
+            }
+            b'/' if next == Some(b'*') => {
+                let mut depth = 0;
+                while index < bytes.len() {
+                    match &bytes[index..] {
+                        [b'/', b'*', ..] => (depth, index) = (depth + 1, index + 2),
+                        [b'*', b'/', ..] => {
+                            (depth, index) = (depth - 1, index + 2);
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => index += 1,
+                    }
+                }
+            }
+            b'"' => {
+                let end = string_end(bytes, index + 1);
+                tokens.push(Token::Str(Literal { start: index + 1, end, raw: false }));
+                index = end + 1;
+            }
+            b'\'' => {
+                // A char literal, or the lifetime / label `'a`.
+                let rest = &source[index + 1..];
+                let mut chars = rest.chars();
+                index += 1 + match (chars.next(), chars.next()) {
+                    (Some('\\'), _) => rest.get(2..).and_then(|rest| rest.find('\'')).map_or(rest.len(), |end| end + 3),
+                    (Some(char), Some('\'')) => char.len_utf8() + 1,
+                    _ => 0,
+                };
+            }
+            _ if is_ident(byte) => {
+                let start = index;
+                while index < bytes.len() && is_ident(bytes[index]) {
+                    index += 1;
+                }
+                let ident = &source[start..index];
+                match (ident, bytes.get(index)) {
+                    // Byte and C strings can't hold classes, but their content must be skipped.
+                    ("b" | "c", Some(b'"')) => index = string_end(bytes, index + 1) + 1,
+                    ("r" | "br" | "cr", Some(b'"' | b'#')) => {
+                        let hashes = bytes[index..].iter().take_while(|byte| **byte == b'#').count();
+                        // `r#type` is a raw identifier
+                        if bytes.get(index + hashes) != Some(&b'"') {
+                            tokens.push(Token::Ident(ident));
+                            continue;
+                        }
+                        let start = index + hashes + 1;
+                        let closing = format!("\"{}", "#".repeat(hashes));
+                        let end = source[start..].find(&closing).map_or(bytes.len(), |end| start + end);
+                        if ident == "r" {
+                            tokens.push(Token::Str(Literal { start, end, raw: true }));
+                        }
+                        index = (end + closing.len()).min(bytes.len());
+                    }
+                    _ => tokens.push(Token::Ident(ident)),
+                }
+            }
+            _ => {
+                tokens.push(Token::Punct(byte));
+                index += 1;
+            }
+        }
+    }
+    tokens
+}
+
+// Index of the closing quote of the string literal starting at `start`.
+fn string_end(bytes: &[u8], mut start: usize) -> usize {
+    while start < bytes.len() && bytes[start] != b'"' {
+        start += if bytes[start] == b'\\' { 2 } else { 1 };
+    }
+    start.min(bytes.len())
+}
+
+struct LineIndex {
+    starts: Vec<usize>,
+}
+
+impl LineIndex {
+    fn new(source: &str) -> Self {
+        let newlines = source.match_indices('\n').map(|(index, _)| index + 1);
+        Self { starts: std::iter::once(0).chain(newlines).collect() }
+    }
+
+    fn position(&self, source: &str, offset: usize) -> (usize, usize) {
+        let line = self.starts.partition_point(|start| *start <= offset);
+        let column = source[self.starts[line - 1]..offset].chars().count() + 1;
+        (line, column)
+    }
+}
+
+/* ========================================================== */
+/*                       🧪 TESTS 🧪                          */
+/* ========================================================== */
+
+#[test]
+fn skips_comments_and_other_literals() {
+    let source = r##"
+        // tw_merge!("commented")
+        /* #[tw(class = "block /* nested */ comment")] */
+        let lifetime: &'static str = "not-a-class";
+        let quote = '"';
+        tw_join!(r#type, 'x', r#"raw "quoted""#, b"bytes", "a\tb \"q\" c\
+            d");
+        #[tw(default, class = "h-9", other = "ignored")]
+    "##;
+    let classes: Vec<_> = scan_source(source).iter().map(|found| found.class).collect();
+    assert_eq!(classes, ["raw", "\"quoted\"", "a", "b", "c", "d", "h-9"]);
+}
@@ -0,0 +1,97 @@
+use tw_merge::ast::{CssVariable, InvalidClass, parse_class, parse_class_options, parse_classes};
+use tw_merge::merge::{MergeOptions, TwMerger};
+
+#[test]
+fn parse_single_class() {
+    let class = parse_class("dark:hover:!-inset-x-px").unwrap();
+    assert_eq!(class.source, "dark:hover:!-inset-x-px");
+    assert_eq!(class.span, 0..23);
+    assert_eq!(class.variants, vec!["dark", "hover"]);
+    assert_eq!(class.elements, vec!["inset", "x", "px"]);
+    assert!(class.important);
+    assert!(class.negative);
+    assert_eq!(class.arbitrary, None);
+
+    let class = parse_class("[&>*]:m-[2px]").unwrap();
+    assert_eq!(class.variants, vec!["[&>*]"]);
+    assert_eq!(class.elements, vec!["m"]);
+    assert_eq!(class.arbitrary, Some("2px"));
+}
+
+#[test]
+fn parse_single_class_invalid() {
+    assert_eq!(parse_class("data-[key=value"), Err(InvalidClass { source: "data-[key=value", span: 0..15 }));
+    assert!(parse_class("flex p-4").is_err(), "only one class is accepted");
+}
+
+#[test]
+fn parse_with_options() {
+    let options = MergeOptions { prefix: "tw-", separator: "|" };
+    let class = parse_class_options("md|tw-p-4", options).unwrap();
+    assert_eq!(class.variants, vec!["md"]);
+    assert_eq!(class.elements, vec!["p", "4"]);
+
+    assert!(parse_class_options("md:p-4", options).is_err());
+}
+
+#[test]
+fn parse_with_scoped_merger() {
+    let merger = TwMerger::new(MergeOptions { prefix: "tw-", separator: "|" });
+    let _guard = merger.scope();
+    let class = parse_class("md|tw-p-4").unwrap();
+    assert_eq!(class.variants, vec!["md"]);
+    assert_eq!(class.elements, vec!["p", "4"]);
+
+    let classes = parse_classes("md|tw-w-1/2 md:p-4");
+    assert_eq!(classes[0].as_ref().unwrap().elements, vec!["w", "1/2"]);
+    assert!(classes[1].is_err());
+}
+
+#[test]
+fn parse_fractions_and_modifiers() {
+    let class = parse_class("w-1/2").unwrap();
+    assert_eq!(class.elements, vec!["w", "1/2"]);
+    assert_eq!(class.modifier, None);
+    assert_eq!(parse_class("-translate-x-1/3").unwrap().elements, vec!["translate", "x", "1/3"]);
+    assert_eq!(parse_class("aspect-16/9").unwrap().elements, vec!["aspect", "16/9"]);
+
+    let class = parse_class("bg-red-500/50").unwrap();
+    assert_eq!(class.elements, vec!["bg", "red", "500"]);
+    assert_eq!(class.modifier, Some("50"));
+    assert_eq!(parse_class("text-lg/7").unwrap().modifier, Some("7"));
+
+    // Not a fraction value of any group, left as written.
+    let class = parse_class("p-1/2").unwrap();
+    assert_eq!(class.elements, vec!["p", "1"]);
+    assert_eq!(class.modifier, Some("2"));
+}
+
+#[test]
+fn parse_spans() {
+    let input = "  flex bgi123([(]]\n hover:bg-[#fff] ";
+    let classes = parse_classes(input);
+    assert_eq!(classes.len(), 3);
+
+    let flex = classes[0].as_ref().unwrap();
+    assert_eq!(&input[flex.span.clone()], "flex");
+
+    let invalid = classes[1].as_ref().unwrap_err();
+    assert_eq!(&input[invalid.span.clone()], "bgi123([(]]");
+
+    let bg = classes[2].as_ref().unwrap();
+    assert_eq!(bg.span, 20..35);
+    assert_eq!(&input[bg.span.clone()], "hover:bg-[#fff]");
+}
+
+#[test]
+fn parse_css_variable() {
+    let class = parse_class("text-(length:--size)").unwrap();
+    assert_eq!(class.elements, vec!["text"]);
+    assert_eq!(class.arbitrary, None);
+    assert_eq!(class.css_variable, Some(CssVariable { label: Some("length"), name: "--size" }));
+    assert_eq!(class.css_variable.unwrap().to_arbitrary(), "length:var(--size)");
+
+    let mut arbitrary = String::from("var(--previous)");
+    CssVariable { label: None, name: "--brand" }.write_arbitrary(&mut arbitrary);
+    assert_eq!(arbitrary, "var(--brand)");
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "cache")]
+
+use tw_merge::merge::{MergeCache, MergeOptions, TwMerger};
+use tw_merge::tw_merge;
+
+#[test]
+fn explicit_cache() {
+    let cache = MergeCache::new(2);
+    assert_eq!(cache.merge(&["px-2 py-2", "p-4"]), "p-4");
+    assert_eq!(cache.merge(&["px-2 py-2", "p-4"]), "p-4");
+    // Keyed on the slices, not the joined string
+    assert_eq!(cache.merge(&["px-2 py-2 p-4"]), "p-4");
+    // Keyed on the options
+    let options = MergeOptions { prefix: "tw-", separator: ":" };
+    assert_eq!(cache.merge_options(&["px-2 py-2", "p-4"], options), "px-2 py-2 p-4");
+
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses, stats.evictions, stats.len, stats.capacity), (1, 3, 1, 2, 2));
+    assert_eq!(stats.hit_rate(), 0.25);
+
+    cache.clear();
+    assert_eq!(cache.stats(), MergeCache::new(2).stats());
+}
+
+#[test]
+fn disabled_cache() {
+    let cache = MergeCache::new(0);
+    assert_eq!(cache.merge(&["p-2 p-4"]), "p-4");
+    assert_eq!(cache.merge(&["p-2 p-4"]), "p-4");
+    assert_eq!((cache.stats().hits, cache.stats().len), (0, 0));
+}
+
+#[test]
+fn global_cache() {
+    // Unique classes, other tests share the global cache.
+    let class = "global-cache-test p-2";
+    let before = MergeCache::global().stats();
+    assert_eq!(tw_merge!(class, "p-4"), "global-cache-test p-4");
+    assert_eq!(tw_merge!(class, "p-4"), "global-cache-test p-4");
+    assert!(MergeCache::global().stats().hits > before.hits);
+
+    // A scoped merger bypasses the global cache.
+    let _guard = TwMerger::new(MergeOptions { prefix: "tw-", separator: ":" }).scope();
+    assert_eq!(tw_merge!(class, "p-4"), "global-cache-test p-2 p-4");
+}
@@ -0,0 +1,145 @@
+use tw_merge::merge::{ClassPattern, CollisionTable, Element, Rest, Theme, TwMerger};
+
+#[test]
+fn enumerate_groups() {
+    let table = CollisionTable::default();
+    let groups = table.groups();
+
+    assert_eq!(groups.first().map(|group| group.id), Some("aspect"));
+    assert!(groups.iter().any(|group| group.id == "padding-x"));
+    // Each group is listed once, with all of its patterns.
+    assert_eq!(groups.iter().filter(|group| group.id == "display").count(), 1);
+    assert_eq!(table.group("display").map(|group| group.patterns.len()), Some(5));
+    assert!(table.group("not-a-group").is_none());
+}
+
+#[test]
+fn lookup_class() {
+    let table = CollisionTable::default();
+    assert_eq!(table.lookup_class("px-4"), Some("padding-x"));
+    assert_eq!(table.lookup_class("hover:bg-red-500"), Some("background-color"));
+    assert_eq!(table.lookup_class("bg-(--brand)"), Some("background-color"));
+    assert_eq!(table.lookup_class("decoration-white"), Some("text-decoration-color"));
+    assert_eq!(table.lookup_class("border-l-white"), Some("border-color-l"));
+    assert_eq!(table.lookup_class("border-r-white"), Some("border-color-r"));
+    assert_eq!(table.lookup_class("border-b-white"), Some("border-color-b"));
+    assert_eq!(table.lookup_class("not-a-class"), None);
+}
+
+#[test]
+fn properties() {
+    let mut table = CollisionTable::default();
+    assert_eq!(table.property("color"), Some("text-color"));
+    assert_eq!(table.property("paint-order"), None);
+    assert!(table.properties().any(|property| property == ("padding-inline", "padding-x")));
+    assert_eq!(table.lookup_class("[color:red]"), Some("text-color"));
+    assert_eq!(table.lookup_class("[paint-order:normal]"), None);
+
+    table.add_property("paint-order", "stroke");
+    assert!(table.remove_property("color"));
+    assert!(!table.remove_property("color"));
+
+    let merger = TwMerger::default().with_collision_table(table);
+    assert_eq!(merger.merge(&["stroke-red-500 [paint-order:normal]"]), "[paint-order:normal]");
+    assert_eq!(merger.merge(&["text-red-500 [color:blue]"]), "text-red-500 [color:blue]");
+}
+
+#[test]
+fn negatives() {
+    let mut table = CollisionTable::default();
+    assert!(table.allows_negative("margin-top"));
+    assert!(!table.allows_negative("background-color"));
+    assert_eq!(table.lookup_class("-mt-2"), Some("margin-top"));
+    assert_eq!(table.lookup_class("-bg-red-500"), None);
+    assert_eq!(table.lookup_class("-bg-linear-45"), Some("background-image"));
+    assert_eq!(table.lookup_class("-bg-none"), None);
+
+    table.set_negative("padding", true);
+    table.set_negative("margin-top", false);
+    assert_eq!(table.lookup_class("-p-2"), Some("padding"));
+    assert_eq!(table.lookup_class("-mt-2"), None);
+
+    let merger = TwMerger::default().with_collision_table(table);
+    assert_eq!(merger.merge(&["p-2 -p-4"]), "-p-4");
+    assert_eq!(merger.merge(&["mt-2 -mt-4"]), "mt-2 -mt-4");
+}
+
+#[test]
+fn modifiers_and_fractions() {
+    let mut table = CollisionTable::default();
+    assert!(table.accepts_modifier("background-color"));
+    assert!(!table.accepts_modifier("padding"));
+    assert!(table.accepts_fraction("width"));
+    assert!(!table.accepts_fraction("padding"));
+    assert_eq!(table.lookup_class("bg-red-500/50"), Some("background-color"));
+    assert_eq!(table.lookup_class("w-1/2"), Some("width"));
+    assert_eq!(table.lookup_class("p-4/50"), None);
+    assert_eq!(table.lookup_class("flex/20"), None);
+
+    table.set_modifier("padding", true);
+    table.set_fraction("width", false);
+    assert_eq!(table.lookup_class("p-4/50"), Some("padding"));
+    assert_eq!(table.lookup_class("w-1/2"), None);
+
+    let merger = TwMerger::default().with_collision_table(table);
+    assert_eq!(merger.merge(&["p-2 p-4/50"]), "p-4/50");
+}
+
+#[test]
+fn edges() {
+    let table = CollisionTable::default();
+    assert_eq!(table.collisions("padding-x"), ["padding-right", "padding-left"]);
+    assert!(table.collisions("flex-direction").is_empty());
+    assert!(table.edges().any(|edge| edge == ("size", "width")));
+}
+
+#[test]
+fn add_group() {
+    let mut table = CollisionTable::default();
+    // Checked before `text-color`
+    table.add_group("text-brand", [ClassPattern::new(&[Element::Is("text"), Element::Is("brand")])]);
+    table.add_collisions("text-brand", ["text-color"]);
+
+    let merger = TwMerger::default().with_collision_table(table);
+    assert_eq!(merger.merge(&["text-white text-brand"]), "text-brand");
+    assert_eq!(merger.merge(&["text-brand text-white"]), "text-brand text-white");
+}
+
+#[test]
+fn replace_group() {
+    let mut table = CollisionTable::default();
+    table.replace_group("padding", [ClassPattern::new(&[Element::Is("p"), Element::Is("0")])]);
+    assert_eq!(table.lookup(&["p", "0"], None), Some("padding"));
+    assert_eq!(table.lookup(&["p", "4"], None), None);
+    // Replacing keeps the priority of the group.
+    let groups = table.groups();
+    let position = |id: &str| groups.iter().position(|group| group.id == id);
+    assert!(position("padding") < position("padding-left"));
+
+    let merger = TwMerger::default().with_collision_table(table);
+    assert_eq!(merger.merge(&["px-2 p-0"]), "p-0");
+    assert_eq!(merger.merge(&["p-2 p-4"]), "p-2 p-4");
+}
+
+#[test]
+fn remove_group() {
+    let mut table = CollisionTable::default();
+    assert!(table.remove_group("font-weight"));
+    assert!(!table.remove_group("font-weight"));
+    table.remove_collisions("padding");
+
+    let merger = TwMerger::default().with_collision_table(table);
+    assert_eq!(merger.merge(&["font-bold font-thin"]), "font-bold font-thin");
+    assert_eq!(merger.merge(&["px-2 p-4"]), "px-2 p-4");
+}
+
+#[test]
+fn theme_uses_merger_table() {
+    let mut table = CollisionTable::empty();
+    table.add_group("font-size", [ClassPattern::new(&[Element::Is("text")]).with_rest(Rest::Any)]);
+
+    let merger =
+        TwMerger::default().with_collision_table(table).with_theme(Theme::default().with_font_size(["display"]));
+    assert_eq!(merger.merge(&["text-lg text-display"]), "text-display");
+    assert_eq!(merger.merge(&["p-2 p-4"]), "p-2 p-4");
+}
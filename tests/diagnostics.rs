@@ -0,0 +1,177 @@
+use tw_merge::merge::{
+    ClassDiagnostic, INVALID_SYNTAX, INVALID_VALUE, MergeOptions, Theme, TwMerger, UNKNOWN_UTILITY,
+    UNSUPPORTED_MODIFIER, UNSUPPORTED_NEGATIVE, merge_lenient, merge_strict, merge_strict_options,
+};
+
+#[test]
+fn strict_ok() {
+    assert_eq!(
+        merge_strict("hover:p-2 p-2 p-4 [color:red] group/card"),
+        Ok("hover:p-2 p-4 [color:red] group/card".into())
+    );
+}
+
+#[test]
+fn strict_errors() {
+    let diagnostics = merge_strict("felx p-4 z-top bg-[red").unwrap_err();
+    assert_eq!(
+        diagnostics,
+        vec![
+            ClassDiagnostic {
+                class: "felx",
+                span: 0..4,
+                error: UNKNOWN_UTILITY,
+                value: Some("felx"),
+                group: None,
+                negative: false
+            },
+            ClassDiagnostic {
+                class: "z-top",
+                span: 9..14,
+                error: INVALID_VALUE,
+                value: Some("top"),
+                group: Some("z-index"),
+                negative: false
+            },
+            ClassDiagnostic {
+                class: "bg-[red",
+                span: 15..22,
+                error: INVALID_SYNTAX,
+                value: None,
+                group: None,
+                negative: false
+            },
+        ]
+    );
+}
+
+#[test]
+fn strict_colors() {
+    assert!(merge_strict("bg-red-500 text-white/50 border-x-transparent fill-none accent-auto bg-[#fff]").is_ok());
+    assert!(merge_strict("bg-(--brand) decoration-current ring-offset-black [color:red]").is_ok());
+
+    let diagnostics = merge_strict("bg-rde-500 felx hover:border-r-blu-500").unwrap_err();
+    assert_eq!(
+        diagnostics.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        [
+            "0..10 `bg-rde-500`: Invalid Tailwind utility value `rde-500` (background-color)",
+            "11..15 `felx`: Invalid Tailwind class `felx`",
+            "16..38 `hover:border-r-blu-500`: Invalid Tailwind utility value `blu-500` (border-color-r)",
+        ]
+    );
+
+    // The v4 neutral palettes
+    assert!(merge_strict("bg-mauve-500 text-olive-300 border-mist-950 fill-taupe-50").is_ok());
+
+    // Custom colors are only known to the theme.
+    let merger = TwMerger::default().with_theme(Theme::default().with_colors(["brand"]));
+    assert_eq!(merger.merge_strict(&["bg-red-500 bg-brand"]), Ok("bg-brand".into()));
+    assert_eq!(merger.merge_strict(&["bg-brnad"]).unwrap_err()[0].value, Some("brnad"));
+}
+
+#[test]
+fn strict_negative() {
+    assert_eq!(merge_strict("-mt-2 -z-10 -translate-x-1/2"), Ok("-mt-2 -z-10 -translate-x-1/2".into()));
+    assert!(merge_strict("-bg-linear-45 -bg-conic-45 -mask-linear-45 -mask-conic-45").is_ok());
+    // Only the angles are negative.
+    for class in ["-bg-none", "-bg-[url(/a.png)]", "-bg-linear-to-r", "-mask-none", "-mask-conic-from-10%"] {
+        assert_eq!(merge_strict(class).unwrap_err()[0].error, UNSUPPORTED_NEGATIVE, "{class}");
+    }
+
+    let diagnostics = merge_strict("-bg-red-500 -felx").unwrap_err();
+    assert_eq!(
+        diagnostics,
+        vec![
+            ClassDiagnostic {
+                class: "-bg-red-500",
+                span: 0..11,
+                error: UNSUPPORTED_NEGATIVE,
+                value: None,
+                group: Some("background-color"),
+                negative: true,
+            },
+            ClassDiagnostic {
+                class: "-felx",
+                span: 12..17,
+                error: UNKNOWN_UTILITY,
+                value: Some("felx"),
+                group: None,
+                negative: true
+            },
+        ]
+    );
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "0..11 `-bg-red-500`: Negative value not supported by Tailwind utility (background-color)"
+    );
+}
+
+#[test]
+fn strict_modifiers() {
+    assert!(merge_strict("bg-red-500/50 text-lg/7 shadow-lg/20 w-1/2 aspect-16/9 group/card").is_ok());
+
+    let diagnostics = merge_strict("p-4/50 z-20/3 -z-1/2 flex/20").unwrap_err();
+    assert_eq!(
+        diagnostics.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        [
+            "0..6 `p-4/50`: Modifier not supported by Tailwind utility `50` (padding)",
+            "7..13 `z-20/3`: Modifier not supported by Tailwind utility `3` (z-index)",
+            "14..20 `-z-1/2`: Modifier not supported by Tailwind utility `2` (z-index)",
+            "21..28 `flex/20`: Modifier not supported by Tailwind utility `20` (display)",
+        ]
+    );
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.error == UNSUPPORTED_MODIFIER));
+}
+
+#[test]
+fn strict_options() {
+    let options = MergeOptions { prefix: "tw-", separator: ":" };
+    assert_eq!(merge_strict_options("tw-p-2 tw-p-4", options), Ok("tw-p-4".into()));
+    assert_eq!(merge_strict_options("p-4", options).unwrap_err()[0].class, "p-4");
+}
+
+#[test]
+fn lenient_keeps_unknown_classes() {
+    let (merged, diagnostics) = merge_lenient("my-card p-2 felx p-4");
+    assert_eq!(merged, "my-card felx p-4");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].to_string(), "12..16 `felx`: Invalid Tailwind class `felx`");
+}
+
+#[test]
+fn merger_spans_joined_inputs() {
+    let merger = TwMerger::default().with_collision_id_fn(|elements: &[&str], _: Option<&str>| match elements {
+        ["btn", ..] => Some("btn"),
+        _ => None,
+    });
+    let (merged, diagnostics) = merger.merge_lenient(&["btn-primary p-2", "felx btn-secondary"]);
+    assert_eq!(merged, "p-2 felx btn-secondary");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].span, 16..20);
+
+    let _guard = merger.scope();
+    assert!(merge_strict("btn-primary").is_ok());
+}
+
+#[test]
+fn negative_theme_and_custom_classes() {
+    let merger = TwMerger::default().with_theme(Theme::default().with_colors(["brand"])).with_collision_id_fn(
+        |elements: &[&str], _: Option<&str>| match elements {
+            ["btn", ..] => Some("btn"),
+            _ => None,
+        },
+    );
+    assert_eq!(merger.merge(&["-bg-brand bg-red-500"]), "-bg-brand bg-red-500");
+    assert_eq!(merger.merge(&["-btn-primary btn-secondary"]), "-btn-primary btn-secondary");
+
+    let diagnostics = merger.merge_strict(&["-bg-brand -btn-primary"]).unwrap_err();
+    assert_eq!(diagnostics[0].error, UNSUPPORTED_NEGATIVE);
+    assert_eq!(diagnostics[0].group, Some("background-color"));
+    assert_eq!(diagnostics[1].error, UNSUPPORTED_NEGATIVE);
+    assert_eq!(diagnostics[1].group, Some("btn"));
+
+    let mut table = merger.collision_table().clone();
+    table.set_negative("btn", true);
+    let merger = merger.with_collision_table(table);
+    assert_eq!(merger.merge(&["-btn-primary btn-secondary"]), "btn-secondary");
+}
@@ -0,0 +1,68 @@
+use tw_merge::merge::{ClassStatus, DroppedBy, MergeOptions, TwMerger, merge_explain, merge_explain_options};
+
+#[test]
+fn explains_kept_and_dropped() {
+    let explanation = merge_explain("p-2 hover:p-2 px-4 p-4 !p-1 unknown-class");
+    assert_eq!(explanation.merged(), "hover:p-2 p-4 !p-1 unknown-class");
+    assert_eq!(
+        explanation.to_string(),
+        "p-2: dropped by p-4 (padding)
+hover:p-2: kept (padding)
+px-4: dropped by p-4 (padding -> padding-x)
+p-4: kept (padding)
+!p-1: kept (padding)
+unknown-class: kept (unknown)"
+    );
+
+    let px = &explanation.classes[2];
+    assert_eq!(px.collision_id, Some("padding-x"));
+    assert_eq!(px.status, ClassStatus::Dropped(DroppedBy { index: 3, class: "p-4", via: "padding" }));
+    assert!(explanation.classes[4].important);
+    assert_eq!(explanation.dropped().count(), 2);
+}
+
+#[test]
+fn explains_negative() {
+    let explanation = merge_explain("-mt-2 mt-4");
+    assert_eq!(explanation.to_string(), "-mt-2: dropped by mt-4 (margin-top)\nmt-4: kept (margin-top)");
+    assert!(explanation.classes[0].negative);
+    assert!(!explanation.classes[1].negative);
+}
+
+#[test]
+fn explains_variants_key() {
+    let explanation = merge_explain("hover:focus:bg-red-500 focus:hover:bg-blue-500");
+    assert_eq!(explanation.classes[0].variants, vec!["focus", "hover"]);
+    assert_eq!(
+        explanation.classes[0].status,
+        ClassStatus::Dropped(DroppedBy { index: 1, class: "focus:hover:bg-blue-500", via: "background-color" })
+    );
+}
+
+#[test]
+fn explains_arbitrary_properties() {
+    let explanation = merge_explain("[color:red] [color:blue]");
+    assert_eq!(
+        explanation.to_string(),
+        "[color:red]: dropped by [color:blue] (text-color)\n[color:blue]: kept (text-color)"
+    );
+
+    let explanation = merge_explain("[paint-order:markers] [paint-order:normal]");
+    assert_eq!(
+        explanation.to_string(),
+        "[paint-order:markers]: dropped by [paint-order:normal] (paint-order)\n[paint-order:normal]: kept (paint-order)"
+    );
+}
+
+#[test]
+fn explains_with_options_and_merger() {
+    let options = MergeOptions { prefix: "tw-", separator: "|" };
+    let explanation = merge_explain_options("md|tw-p-2 md|tw-p-4", options);
+    assert_eq!(explanation.merged(), "md|tw-p-4");
+
+    let merger = TwMerger::new(options);
+    assert_eq!(merger.explain(&["md|tw-p-2", "md|tw-p-4"]), explanation);
+
+    let _guard = merger.scope();
+    assert_eq!(merge_explain("md|tw-p-2 md|tw-p-4"), explanation);
+}
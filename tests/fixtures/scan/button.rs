@@ -0,0 +1,21 @@
+use tw_merge::*;
+
+#[derive(TwClass)]
+#[tw(class = "inline-flex items-center")]
+struct Btn {
+    size: BtnSize,
+}
+
+#[derive(TwVariant)]
+enum BtnSize {
+    #[tw(default, class = "h-9 px-4")]
+    Default,
+    #[tw(class = "h-8 px-3")]
+    Sm,
+}
+
+// tw_merge!("commented-out")
+fn class(active: bool) -> String {
+    let label = "not-a-class";
+    tw_merge!("px-4", active.then_some("font-bold"), label)
+}
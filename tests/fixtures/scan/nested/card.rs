@@ -0,0 +1,5 @@
+const CARD: &str = tw_merge!("rounded-lg p-6", "p-4");
+
+fn card() -> String {
+    tw_join!("flex", r"hover:bg-[url('/img.png')]")
+}
@@ -0,0 +1,10 @@
+#[derive(TwClass)]
+#[tw(class = "text-white p-4 flex")]
+struct Card {
+    size: CardSize,
+}
+
+fn class() -> String {
+    // `p-4` only overrides `px-2` because it comes later, left as is.
+    tw_merge!("px-2 p-4", "hover:underline md:block")
+}
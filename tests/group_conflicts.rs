@@ -215,6 +215,41 @@ fn text_color_font_size() {
     assert_eq!(merge_classes("text-red-500/10 text-lg/9"), "text-red-500/10 text-lg/9");
 }
 
+#[test]
+fn postfix_modifiers() {
+    assert_eq!(merge_classes("bg-red-500/50 bg-red-500/20"), "bg-red-500/20");
+    assert_eq!(merge_classes("bg-red-500/50 bg-blue-500"), "bg-blue-500");
+    assert_eq!(merge_classes("bg-[#000] bg-[#fff]/[0.3]"), "bg-[#fff]/[0.3]");
+    assert_eq!(merge_classes("bg-(--brand)/50 bg-red-500"), "bg-red-500");
+    assert_eq!(merge_classes("text-sm/[17px] text-lg"), "text-lg");
+    assert_eq!(merge_classes("text-sm/6 text-red-500/10"), "text-sm/6 text-red-500/10");
+
+    // font-size with a line-height modifier
+    assert_eq!(merge_classes("leading-9 text-lg/7"), "text-lg/7");
+    assert_eq!(merge_classes("text-lg/7 leading-9"), "text-lg/7 leading-9");
+    assert_eq!(merge_classes("text-lg/7 text-xl"), "text-xl");
+
+    // Fractions are values, not modifiers
+    assert_eq!(merge_classes("w-1/2 w-1/3"), "w-1/3");
+    assert_eq!(merge_classes("aspect-16/9 aspect-4/3"), "aspect-4/3");
+    assert_eq!(merge_classes("w-full w-1/2"), "w-1/2");
+    assert_eq!(merge_classes("-translate-x-1/2 translate-x-0"), "translate-x-0");
+
+    // Only colors, shadows, font sizes, gradients and named groups take a modifier
+    assert_eq!(merge_classes("shadow-lg shadow-md/20"), "shadow-md/20");
+    assert_eq!(merge_classes("group/card group/item"), "group/item");
+    assert_eq!(merge_classes("p-2 p-4/50"), "p-2 p-4/50");
+    assert_eq!(merge_classes("z-10 z-20/3"), "z-10 z-20/3");
+    assert_eq!(merge_classes("z-4 -z-1/2"), "z-4 -z-1/2");
+    assert_eq!(merge_classes("block flex/20"), "block flex/20");
+    assert_eq!(merge_classes("w-1/2 w-full/2"), "w-1/2 w-full/2");
+    assert_eq!(merge_classes("p-2 p-1/2"), "p-2 p-1/2");
+
+    // Unterminated modifiers are not classes, even with a trailing escape
+    assert_eq!(merge_classes("bg-red-500/[0.5\\ bg-blue-500"), "bg-red-500/[0.5\\ bg-blue-500");
+    assert_eq!(merge_classes("text-lg/(--lh\\ text-xl"), "text-lg/(--lh\\ text-xl");
+}
+
 #[test]
 fn stroke_width() {
     assert_eq!(merge_classes("stroke-2 stroke-[3]"), "stroke-[3]");
@@ -1,4 +1,6 @@
-use tw_merge::merge::merge_classes;
+use std::borrow::Cow;
+
+use tw_merge::merge::{MergeOptions, merge_classes, merge_cow, merge_cow_options, merge_into};
 use tw_merge::tw_merge;
 
 #[test]
@@ -158,6 +160,24 @@ fn test_conflict_with_arbitrary_values() {
     assert_eq!(result, "bg-gradient-to-r");
 }
 
+#[test]
+fn test_css_variable_shorthand() {
+    assert_eq!(merge_classes("bg-red-500 bg-(--brand)"), "bg-(--brand)");
+    assert_eq!(merge_classes("bg-(--brand) bg-[var(--other)]"), "bg-[var(--other)]");
+    assert_eq!(merge_classes("p-2 px-4 p-(--gutter)"), "p-(--gutter)");
+    assert_eq!(merge_classes("hover:mt-2 hover:mt-(--gap)"), "hover:mt-(--gap)");
+
+    // Without a type hint text-(--x) is a color, like text-[var(--x)]
+    assert_eq!(merge_classes("text-red-500 text-(--fg)"), "text-(--fg)");
+    assert_eq!(merge_classes("text-lg text-(--fg)"), "text-lg text-(--fg)");
+    assert_eq!(merge_classes("text-lg text-(length:--size)"), "text-(length:--size)");
+    assert_eq!(merge_classes("text-(length:--size) text-[length:var(--other)]"), "text-[length:var(--other)]");
+
+    // An unterminated shorthand is not a class, even with a trailing escape
+    assert_eq!(merge_classes("bg-red-500 bg-(--x\\"), "bg-red-500 bg-(--x\\");
+    assert_eq!(merge_classes("bg-(--x\\ p-2"), "bg-(--x\\ p-2");
+}
+
 #[test]
 fn test_merge_with_non_tailwind() {
     let class_names: String = vec![
@@ -242,6 +262,14 @@ fn handles_color_conflicts_properly() {
     assert_eq!(merge_classes("stroke-[hsl(350_80%_0%)] stroke-[10px]"), "stroke-[hsl(350_80%_0%)] stroke-[10px]");
 }
 
+#[test]
+fn merges_text_decoration_colors_only_with_colors() {
+    assert_eq!(merge_classes("decoration-red-500 decoration-blue-500"), "decoration-blue-500");
+    assert_eq!(merge_classes("decoration-red-500 decoration-[#fff]"), "decoration-[#fff]");
+    assert_eq!(merge_classes("decoration-red-500 decoration-2"), "decoration-red-500 decoration-2");
+    assert_eq!(merge_classes("decoration-red-500 decoration-lg"), "decoration-red-500 decoration-lg");
+}
+
 #[test]
 fn merges_content_utilities_correctly() {
     assert_eq!(merge_classes("content-['hello'] content-[attr(data-content)]"), "content-[attr(data-content)]");
@@ -263,6 +291,10 @@ fn merges_classes_with_per_side_border_colors_correctly() {
     assert_eq!(merge_classes("border-t-some-blue border-y-some-blue"), "border-y-some-blue");
 
     assert_eq!(merge_classes("border-l-some-blue border-x-some-blue"), "border-x-some-blue");
+
+    assert_eq!(merge_classes("border-r-2 border-r-white"), "border-r-2 border-r-white");
+    assert_eq!(merge_classes("border-b-4 border-b-red-500 border-b-2"), "border-b-red-500 border-b-2");
+    assert_eq!(merge_classes("border-r-black border-r-white"), "border-r-white");
 }
 
 #[test]
@@ -273,6 +305,26 @@ fn test_data_attributes() {
     );
 }
 
+#[test]
+fn sorts_variants_before_comparing() {
+    assert_eq!(merge_classes("hover:md:p-2 md:hover:p-4"), "md:hover:p-4");
+    assert_eq!(
+        merge_classes("dark:focus:hover:bg-red-500 hover:dark:focus:bg-blue-500"),
+        "hover:dark:focus:bg-blue-500"
+    );
+    assert_eq!(merge_classes("hover:md:!p-2 md:hover:!p-4"), "md:hover:!p-4");
+    assert_eq!(merge_classes("hover:md:[color:red] md:hover:[color:blue]"), "md:hover:[color:blue]");
+}
+
+#[test]
+fn keeps_order_sensitive_variants() {
+    assert_eq!(merge_classes("hover:*:p-2 *:hover:p-4"), "hover:*:p-2 *:hover:p-4");
+    assert_eq!(merge_classes("hover:before:p-2 before:hover:p-4"), "hover:before:p-2 before:hover:p-4");
+    assert_eq!(merge_classes("md:group-hover:p-2 group-hover:md:p-4"), "md:group-hover:p-2 group-hover:md:p-4");
+    assert_eq!(merge_classes("md:peer-focus:p-2 peer-focus:md:p-4"), "md:peer-focus:p-2 peer-focus:md:p-4");
+    assert_eq!(merge_classes("focus:[&>*]:p-2 [&>*]:focus:p-4"), "focus:[&>*]:p-2 [&>*]:focus:p-4");
+}
+
 #[test]
 fn basic_arbitrary_variants() {
     assert_eq!(merge_classes("[&>*]:underline [&>*]:line-through"), "[&>*]:line-through");
@@ -340,8 +392,12 @@ fn multiple_arbitrary_variants() {
         merge_classes("[&>*]:[&_div]:underline [&_div]:[&>*]:line-through"),
         "[&>*]:[&_div]:underline [&_div]:[&>*]:line-through"
     );
-    // What is this test even doing?
-    // assert_eq!(merge_classes("hover:dark:[&>*]:focus:disabled:[&_div]:underline dark:hover:[&>*]:disabled:focus:[&_div]:line-through"), "dark:hover:[&>*]:disabled:focus:[&_div]:line-through");
+    assert_eq!(
+        merge_classes(
+            "hover:dark:[&>*]:focus:disabled:[&_div]:underline dark:hover:[&>*]:disabled:focus:[&_div]:line-through"
+        ),
+        "dark:hover:[&>*]:disabled:focus:[&_div]:line-through"
+    );
     assert_eq!(
         merge_classes(
             "hover:dark:[&>*]:focus:[&_div]:disabled:underline dark:hover:[&>*]:disabled:focus:[&_div]:line-through"
@@ -413,6 +469,20 @@ fn arbitrary_property_conflicts() {
     assert_eq!(result, "hover:[paint-order:normal]");
 }
 
+#[test]
+fn test_arbitrary_properties_collide_with_utilities() {
+    assert_eq!(merge_classes("text-red-500 [color:blue]"), "[color:blue]");
+    assert_eq!(merge_classes("[color:blue] text-red-500"), "text-red-500");
+    assert_eq!(merge_classes("px-2 py-1 [padding:0]"), "[padding:0]");
+    assert_eq!(merge_classes("[padding:0] px-2"), "[padding:0] px-2");
+    assert_eq!(merge_classes("[padding-left:0] p-2"), "p-2");
+    assert_eq!(merge_classes("flex [display:grid]"), "[display:grid]");
+    assert_eq!(merge_classes("hover:text-red-500 [color:blue]"), "hover:text-red-500 [color:blue]");
+    assert_eq!(merge_classes("hover:text-red-500 hover:[color:blue]"), "hover:[color:blue]");
+    assert_eq!(merge_classes("text-lg [color:blue]"), "text-lg [color:blue]");
+    assert_eq!(merge_classes("[paint-order:markers] [paint-order:normal]"), "[paint-order:normal]");
+}
+
 #[test]
 fn test_negative_values() {
     let class = "top-12 -top-69";
@@ -422,4 +492,50 @@ fn test_negative_values() {
     let class = "-top-12 -top-2000";
     let result = merge_classes(class);
     assert_eq!(result, "-top-2000");
+    // Negative and positive forms of a utility are one group.
+    assert_eq!(merge_classes("-mt-2 mt-4"), "mt-4");
+    assert_eq!(merge_classes("mt-4 -mt-2"), "-mt-2");
+    assert_eq!(merge_classes("-translate-x-1 translate-x-2"), "translate-x-2");
+    assert_eq!(merge_classes("-m-[3px] m-2"), "m-2");
+    assert_eq!(merge_classes("z-20 -z-10"), "-z-10");
+    assert_eq!(merge_classes("m-2 -mx-4"), "m-2 -mx-4");
+    assert_eq!(merge_classes("-mx-4 m-2"), "m-2");
+
+    // Utilities without negative values don't collide, the class is invalid.
+    assert_eq!(merge_classes("-bg-red-500 bg-blue-500"), "-bg-red-500 bg-blue-500");
+    assert_eq!(merge_classes("p-2 -p-4"), "p-2 -p-4");
+
+    // Gradient and mask angles can be negative.
+    assert_eq!(merge_classes("bg-linear-45 -bg-linear-45"), "-bg-linear-45");
+    assert_eq!(merge_classes("-bg-conic-45 bg-none"), "bg-none");
+    assert_eq!(merge_classes("mask-linear-45 -mask-linear-45"), "-mask-linear-45");
+    assert_eq!(merge_classes("mask-conic-45 -mask-conic-45"), "-mask-conic-45");
+}
+
+#[test]
+fn test_merge_cow() {
+    assert!(matches!(merge_cow("flex p-4 hover:p-2 my-class"), Cow::Borrowed(_)));
+    assert!(matches!(merge_cow(""), Cow::Borrowed("")));
+    assert!(matches!(merge_cow("p-2 p-4"), Cow::Owned(class) if class == "p-4"));
+    assert!(matches!(merge_cow("flex  p-4"), Cow::Owned(class) if class == "flex p-4"));
+    assert!(matches!(merge_cow("flex\np-4 "), Cow::Owned(class) if class == "flex p-4"));
+
+    let options = MergeOptions { prefix: "tw-", separator: ":" };
+    assert!(matches!(merge_cow_options("tw-p-2 p-2", options), Cow::Borrowed(_)));
+    assert_eq!(merge_cow_options("tw-p-2 tw-p-4", options), "tw-p-4");
+}
+
+#[test]
+fn test_merge_into() {
+    let mut buffer = String::from("previous content");
+    merge_into("p-2 flex p-4", &mut buffer);
+    assert_eq!(buffer, "flex p-4");
+
+    let capacity = buffer.capacity();
+    merge_into("block", &mut buffer);
+    assert_eq!(buffer, "block");
+    assert_eq!(buffer.capacity(), capacity);
+
+    merge_into("", &mut buffer);
+    assert_eq!(buffer, "");
 }
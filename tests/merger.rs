@@ -0,0 +1,87 @@
+use tw_merge::merge::{MergeOptions, TwMerger, merge_classes, merge_cow, merge_into};
+use tw_merge::tw_merge;
+
+const BRAND: MergeOptions = MergeOptions { prefix: "brand-", separator: ":" };
+const ADMIN: MergeOptions = MergeOptions { prefix: "admin-", separator: "|" };
+
+#[test]
+fn independent_mergers() {
+    let brand = TwMerger::new(BRAND);
+    let admin = TwMerger::new(ADMIN);
+
+    assert_eq!(brand.merge(&["hover:brand-p-2 brand-p-2", "brand-p-4"]), "hover:brand-p-2 brand-p-4");
+    assert_eq!(admin.merge(&["hover|admin-p-2 admin-p-2", "admin-p-4"]), "hover|admin-p-2 admin-p-4");
+
+    // Each merger only knows its own prefix
+    assert_eq!(brand.merge(&["admin-p-2 admin-p-4"]), "admin-p-2 admin-p-4");
+}
+
+#[test]
+fn explicit_merger_in_macro() {
+    let brand = TwMerger::new(BRAND);
+    assert_eq!(tw_merge!(brand => "brand-bg-black", "brand-bg-white"), "brand-bg-white");
+    assert_eq!(tw_merge!(&brand => "brand-bg-black", "brand-bg-white",), "brand-bg-white");
+}
+
+#[test]
+fn scoped_merger() {
+    let brand = TwMerger::new(BRAND);
+    let admin = TwMerger::new(ADMIN);
+
+    assert_eq!(tw_merge!("brand-p-2", "brand-p-4"), "brand-p-2 brand-p-4");
+    {
+        let _brand = brand.scope();
+        assert_eq!(tw_merge!("brand-p-2", "brand-p-4"), "brand-p-4");
+        {
+            let _admin = admin.scope();
+            assert_eq!(merge_classes("admin-p-2 admin-p-4 brand-p-2 brand-p-4"), "admin-p-4 brand-p-2 brand-p-4");
+        }
+        assert_eq!(tw_merge!("brand-p-2", "brand-p-4"), "brand-p-4");
+    }
+    assert_eq!(tw_merge!("brand-p-2", "brand-p-4"), "brand-p-2 brand-p-4");
+}
+
+#[test]
+fn scope_is_per_thread() {
+    let brand = TwMerger::new(BRAND);
+    let _brand = brand.scope();
+
+    let other = std::thread::spawn(|| tw_merge!("brand-p-2", "brand-p-4")).join().unwrap();
+    assert_eq!(other, "brand-p-2 brand-p-4");
+}
+
+#[test]
+fn merger_with_overrides() {
+    let merger = TwMerger::default()
+        .with_collision_id_fn(|elements: &[&str], _: Option<&str>| match elements {
+            ["btn", ..] => Some("btn"),
+            ["card"] => Some("card"),
+            _ => None,
+        })
+        .with_collisions_fn(|collision_id: &str| -> Option<&'static [&'static str]> {
+            match collision_id {
+                "card" => Some(&["btn", "padding"]),
+                _ => None,
+            }
+        });
+
+    assert_eq!(merger.merge(&["btn-primary btn-secondary"]), "btn-secondary");
+    assert_eq!(merger.merge(&["btn-primary p-4 card"]), "card");
+    assert_eq!(merger.merge(&["p-2 p-4"]), "p-4", "default rules still apply");
+}
+
+#[test]
+fn merger_cow_and_into() {
+    let brand = TwMerger::new(BRAND);
+    assert_eq!(brand.merge_cow("brand-p-2 brand-p-4"), "brand-p-4");
+    assert!(matches!(brand.merge_cow("brand-p-2 p-4"), std::borrow::Cow::Borrowed(_)));
+
+    let mut buffer = String::new();
+    brand.merge_into(&["brand-p-2", "brand-p-4"], &mut buffer);
+    assert_eq!(buffer, "brand-p-4");
+
+    let _guard = brand.scope();
+    assert_eq!(merge_cow("brand-p-2 brand-p-4"), "brand-p-4");
+    merge_into("brand-m-2 brand-m-4", &mut buffer);
+    assert_eq!(buffer, "brand-m-4");
+}
@@ -16,9 +16,9 @@ fn test_collisions() {
         }
     }
 
-    pub fn get_collisions(collision_id: &str) -> Option<Vec<&'static str>> {
+    pub fn get_collisions(collision_id: &str) -> Option<&'static [&'static str]> {
         match collision_id {
-            "florida" => Some(vec!["america"]),
+            "florida" => Some(&["america"]),
             _ => None,
         }
     }
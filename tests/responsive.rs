@@ -0,0 +1,89 @@
+use tw_merge::merge::{MergeOptions, TwMerger};
+use tw_merge::{AsTailwindClass, Responsive, tw_merge};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum Size {
+    #[default]
+    Sm,
+    Lg,
+    Full,
+}
+
+impl AsTailwindClass for Size {
+    fn as_class(&self) -> &str {
+        match self {
+            Size::Sm => "h-8 px-3",
+            Size::Lg => " h-10 px-8 ",
+            Size::Full => "w-full",
+        }
+    }
+}
+
+#[test]
+fn base_only() {
+    let size = Responsive::from(Size::Lg);
+    assert_eq!(size.as_class(), "h-10 px-8");
+    assert_eq!(Responsive::<Size>::default().as_class(), "h-8 px-3");
+    assert_eq!(*size.base(), Size::Lg);
+    assert!(size.breakpoints().is_empty());
+}
+
+#[test]
+fn breakpoints() {
+    let size = Responsive::new(Size::Sm).at("md", Size::Lg).at("xl", Size::Full);
+    assert_eq!(size.as_class(), "h-8 px-3 md:h-10 md:px-8 xl:w-full");
+    assert_eq!(size.breakpoints(), [("md", Size::Lg), ("xl", Size::Full)]);
+
+    // Later values of the same breakpoint win.
+    let size = Responsive::new(Size::Sm).at("md", Size::Lg).at("md", Size::Sm);
+    assert_eq!(size.as_class(), "h-8 px-3 md:h-8 md:px-3");
+
+    assert_eq!(tw_merge!("p-2", size, "md:h-12"), "p-2 h-8 px-3 md:px-3 md:h-12");
+}
+
+#[test]
+fn scoped_options() {
+    let merger = TwMerger::new(MergeOptions { prefix: "tw-", separator: "|" });
+    let _guard = merger.scope();
+
+    #[derive(Clone, Copy)]
+    struct Prefixed(&'static str);
+    impl AsTailwindClass for Prefixed {
+        fn as_class(&self) -> &str {
+            self.0
+        }
+    }
+
+    let size =
+        Responsive::new(Prefixed("tw-px-2")).at("md", Prefixed("tw-px-4 hover|tw-px-8")).at("md", Prefixed("tw-px-6"));
+    assert_eq!(size.as_class(), "tw-px-2 md|hover|tw-px-8 md|tw-px-6");
+}
+
+#[test]
+fn merges_when_rendered() {
+    let size = Responsive::new(Size::Sm).at("md", Size::Full);
+    let merger = TwMerger::new(MergeOptions { prefix: "", separator: "|" });
+    {
+        let _guard = merger.scope();
+        assert_eq!(size.to_class(), "h-8 px-3 md|w-full");
+        assert_eq!(size.as_class(), "h-8 px-3 md|w-full");
+    }
+    assert_eq!(size.to_class(), "h-8 px-3 md:w-full");
+    assert_eq!(size.as_class(), "h-8 px-3 md:w-full");
+
+    // Each scope gets its own result.
+    let other = TwMerger::new(MergeOptions { prefix: "", separator: "_" });
+    {
+        let _guard = other.scope();
+        assert_eq!(size.as_class(), "h-8 px-3 md_w-full");
+    }
+    {
+        let _guard = merger.scope();
+        assert_eq!(size.as_class(), "h-8 px-3 md|w-full");
+    }
+
+    // Adding a breakpoint merges again.
+    let size = size.at("xl", Size::Lg);
+    assert_eq!(size.as_class(), "h-8 px-3 md:w-full xl:h-10 xl:px-8");
+    assert_eq!(size, Responsive::new(Size::Sm).at("md", Size::Full).at("xl", Size::Lg));
+}
@@ -0,0 +1,63 @@
+use std::path::Path;
+
+use tw_merge::merge::TwMerger;
+use tw_merge::scan::{ClassLocation, safelist, scan_paths, sort_source};
+
+#[test]
+fn scan_directory() {
+    let found = scan_paths(["tests/fixtures/scan"]).unwrap();
+    let classes: Vec<&str> = found.iter().map(|found| found.class.as_str()).collect();
+    assert_eq!(
+        classes,
+        [
+            "inline-flex",
+            "items-center",
+            "h-9",
+            "px-4",
+            "h-8",
+            "px-3",
+            "px-4",
+            "font-bold",
+            "rounded-lg",
+            "p-6",
+            "p-4",
+            "flex",
+            "hover:bg-[url('/img.png')]",
+        ]
+    );
+    assert_eq!(
+        found[1],
+        ClassLocation {
+            class: "items-center".into(),
+            path: Path::new("tests/fixtures/scan/button.rs").into(),
+            line: 4,
+            column: 27,
+        }
+    );
+    assert_eq!((found[12].path.file_name().unwrap(), found[12].line, found[12].column), ("card.rs".as_ref(), 4, 24));
+}
+
+#[test]
+fn scan_file() {
+    // Files passed directly are scanned whatever their extension.
+    let found = scan_paths(["tests/fixtures/scan/nested/notes.txt"]).unwrap();
+    assert_eq!(found.len(), 1);
+    assert!(scan_paths(["tests/fixtures/scan/missing.rs"]).is_err());
+}
+
+#[test]
+fn safelist_is_deduplicated() {
+    let found = scan_paths(["tests/fixtures/scan"]).unwrap();
+    let safelist = safelist(found.iter().map(|found| found.class.as_str()));
+    assert_eq!(safelist.lines().count(), 12);
+    assert!(safelist.starts_with("flex\nfont-bold\nh-8\n"));
+}
+
+#[test]
+fn sort_fixture() {
+    let source = std::fs::read_to_string("tests/fixtures/sort/card.rs").unwrap();
+    let sorted = sort_source(&source, &TwMerger::default());
+    assert_eq!(sorted, source.replace("text-white p-4 flex", "flex p-4 text-white"));
+    // Sorting is idempotent.
+    assert_eq!(sort_source(&sorted, &TwMerger::default()), sorted);
+}
@@ -0,0 +1,63 @@
+use tw_merge::merge::{MergeOptions, TwMerger, sort_classes, tw_merge_slice};
+
+#[test]
+fn utilities_in_property_order() {
+    assert_eq!(sort_classes("pt-2 mx-4"), "mx-4 pt-2");
+    assert_eq!(
+        sort_classes("text-white bg-black rounded-lg px-4 flex relative"),
+        "relative flex rounded-lg bg-black px-4 text-white"
+    );
+    assert_eq!(sort_classes("mx-auto container"), "container mx-auto");
+    // Arbitrary properties come after the other utilities.
+    assert_eq!(sort_classes("[mask-type:alpha] z-10"), "z-10 [mask-type:alpha]");
+}
+
+#[test]
+fn unknown_classes_first() {
+    assert_eq!(sort_classes("p-4 card felx bg-[red"), "card felx bg-[red p-4");
+}
+
+#[test]
+fn variants_last() {
+    assert_eq!(sort_classes("md:p-2 hover:p-2 p-2 focus:p-2"), "p-2 hover:p-2 focus:p-2 md:p-2");
+    assert_eq!(sort_classes("md:hover:p-2 md:p-2 hover:p-2"), "hover:p-2 md:p-2 md:hover:p-2");
+    assert_eq!(
+        sort_classes("group-hover:flex [&>*]:flex data-[open]:flex"),
+        "group-hover:flex data-[open]:flex [&>*]:flex"
+    );
+}
+
+#[test]
+fn same_group_keeps_order() {
+    assert_eq!(sort_classes("p-4 flex p-2 !p-1"), "flex p-4 p-2 !p-1");
+    assert_eq!(tw_merge_slice(&[&sort_classes("p-4 flex p-2")]), "flex p-2");
+}
+
+#[test]
+fn colliding_groups_keep_order() {
+    // p-4 knocks out px-2, sorting padding first would keep both.
+    assert_eq!(sort_classes("px-2 p-4"), "px-2 p-4");
+    assert_eq!(sort_classes("pt-2 flex p-4 mx-2"), "mx-2 flex pt-2 p-4");
+    // Only classes with the same variants collide.
+    assert_eq!(sort_classes("md:px-2 p-4 px-2 hover:p-4"), "p-4 px-2 hover:p-4 md:px-2");
+    assert_eq!(sort_classes("text-lg/7 leading-none text-red-500"), "text-lg/7 leading-none text-red-500");
+
+    // The sort keeps the same classes in a merge.
+    let kept = |class: &str| {
+        let mut kept: Vec<String> = tw_merge_slice(&[class]).split(' ').map(String::from).collect();
+        kept.sort();
+        kept
+    };
+    for class in ["px-2 p-4", "pt-2 flex p-4 mx-2", "md:px-2 p-4 px-2 hover:p-4", "text-lg/7 leading-none"] {
+        assert_eq!(kept(&sort_classes(class)), kept(class), "{class}");
+    }
+}
+
+#[test]
+fn merger_options() {
+    let merger = TwMerger::new(MergeOptions { prefix: "tw-", separator: "|" });
+    assert_eq!(merger.sort_classes(&["hover|tw-flex tw-p-4", "tw-block"]), "tw-block tw-p-4 hover|tw-flex");
+
+    let _guard = merger.scope();
+    assert_eq!(sort_classes("tw-p-4 p-4"), "p-4 tw-p-4");
+}
@@ -0,0 +1,175 @@
+use tw_merge::merge::{TailwindConfig, Theme, ThemeError, TwMerger, tw_merge_override};
+
+fn design_system() -> TwMerger {
+    let theme = Theme::default()
+        .with_font_size(["display", "hero-xl"])
+        .with_shadow(["elevated"])
+        .with_drop_shadow(["elevated"])
+        .with_text_shadow(["glow"])
+        .with_radius(["pill"])
+        .with_spacing(["gutter"])
+        .with_colors(["brand", "surface-muted"])
+        .with_breakpoints(["tablet"]);
+    TwMerger::default().with_theme(theme)
+}
+
+#[test]
+fn font_size() {
+    let merger = design_system();
+    assert_eq!(merger.merge(&["text-lg text-display"]), "text-display");
+    assert_eq!(merger.merge(&["text-display text-red-500"]), "text-display text-red-500");
+    assert_eq!(merger.merge(&["text-hero-xl text-display/7 leading-5"]), "text-display/7 leading-5");
+    assert_eq!(merger.merge(&["leading-5 text-display/7"]), "text-display/7");
+}
+
+#[test]
+fn shadow_and_radius() {
+    let merger = design_system();
+    assert_eq!(merger.merge(&["shadow-lg shadow-red-500 shadow-elevated"]), "shadow-red-500 shadow-elevated");
+    assert_eq!(merger.merge(&["drop-shadow-md drop-shadow-elevated"]), "drop-shadow-elevated");
+    assert_eq!(merger.merge(&["text-shadow-lg text-shadow-glow"]), "text-shadow-glow");
+    assert_eq!(merger.merge(&["inset-shadow-sm inset-shadow-elevated"]), "inset-shadow-sm inset-shadow-elevated");
+    assert_eq!(merger.merge(&["rounded-lg rounded-pill"]), "rounded-pill");
+    assert_eq!(merger.merge(&["rounded-t-lg rounded-t-pill rounded-b-pill"]), "rounded-t-pill rounded-b-pill");
+}
+
+#[test]
+fn shadows_per_namespace() {
+    let theme = Theme::default().with_drop_shadow(["soft"]);
+    let merger = TwMerger::default().with_theme(theme);
+    assert_eq!(merger.merge(&["drop-shadow-md drop-shadow-soft"]), "drop-shadow-soft");
+    assert_eq!(merger.merge(&["shadow-md shadow-soft"]), "shadow-md shadow-soft");
+    assert_eq!(merger.merge(&["text-shadow-md text-shadow-soft"]), "text-shadow-md text-shadow-soft");
+
+    let theme = Theme::from_css("@theme { --drop-shadow-soft: 0 1px 2px black; }").unwrap();
+    assert_eq!(theme.drop_shadow, ["soft"].map(String::from).into());
+    assert!(theme.shadow.is_empty());
+
+    let config = TailwindConfig::from_json(r#"{ "theme": { "dropShadow": { "soft": "0 1px 2px black" } } }"#).unwrap();
+    assert_eq!(config.theme.drop_shadow, ["soft"].map(String::from).into());
+    assert!(config.theme.shadow.is_empty());
+}
+
+#[test]
+fn spacing_and_colors() {
+    let merger = design_system();
+    assert_eq!(merger.merge(&["top-4 top-gutter"]), "top-gutter");
+    assert_eq!(merger.merge(&["inset-x-2 inset-x-gutter"]), "inset-x-gutter");
+    assert_eq!(merger.merge(&["text-lg text-brand"]), "text-lg text-brand");
+    assert_eq!(merger.merge(&["border-2 border-red-500 border-surface-muted"]), "border-2 border-surface-muted");
+    assert_eq!(merger.merge(&["bg-brand bg-surface-muted"]), "bg-surface-muted");
+}
+
+#[test]
+fn breakpoints() {
+    let merger = design_system();
+    assert_eq!(merger.merge(&["max-w-lg max-w-screen-tablet"]), "max-w-screen-tablet");
+}
+
+#[test]
+fn without_theme() {
+    let merger = TwMerger::default();
+    assert_eq!(merger.merge(&["text-lg text-display"]), "text-lg text-display");
+    assert_eq!(merger.theme(), None);
+}
+
+#[test]
+fn theme_as_collision_id_fn() {
+    let theme = Theme::default().with_font_size(["display"]);
+    assert_eq!(theme.collision_id(&["text", "display"], None), Some("font-size"));
+    assert_eq!(theme.collision_id(&["text", "display"], Some("12px")), None);
+    assert_eq!(tw_merge_override(&["text-lg text-display"], Default::default(), theme, |_: &str| None), "text-display");
+}
+
+#[test]
+fn from_css() {
+    let theme = Theme::from_css_file("tests/fixtures/app.css").unwrap();
+    assert_eq!(theme.colors, ["brand", "surface-muted"].map(String::from).into());
+    assert_eq!(theme.font_size, ["display"].map(String::from).into());
+    assert_eq!(theme.shadow, ["elevated"].map(String::from).into());
+    assert_eq!(theme.text_shadow, ["glow"].map(String::from).into());
+    assert!(theme.drop_shadow.is_empty());
+    assert!(theme.radius.contains("pill"));
+    assert!(theme.spacing.contains("gutter"));
+    assert!(theme.breakpoints.contains("tablet"));
+    assert!(theme.containers.contains("prose"));
+
+    let merger = TwMerger::default().with_theme(theme);
+    assert_eq!(merger.merge(&["text-lg text-display text-brand"]), "text-display text-brand");
+    assert_eq!(merger.merge(&["max-w-md max-w-prose"]), "max-w-prose");
+}
+
+#[test]
+fn from_css_multiple_blocks() {
+    let theme = Theme::from_css("@theme { --color-brand: red; } @theme inline { --radius-pill: 9999px }").unwrap();
+    assert!(theme.colors.contains("brand"));
+    assert!(theme.radius.contains("pill"));
+}
+
+#[test]
+fn from_css_skips_comments_and_strings() {
+    let css = r#"
+        /* @theme { --color-old: red; } */
+        .icon { content: "@theme { --color-fake: red; }"; }
+        @theme {
+            --color-brand: red;
+            --shadow-icon: url("data:image/svg+xml;utf8,<svg>}</svg>");
+            --radius-pill: 9999px;
+        }
+    "#;
+    let theme = Theme::from_css(css).unwrap();
+    assert_eq!(theme.colors, ["brand"].map(String::from).into());
+    assert!(theme.shadow.contains("icon"));
+    assert!(theme.radius.contains("pill"));
+
+    assert!(matches!(Theme::from_css("/* @theme { --color-brand: red; } */"), Err(ThemeError::NotFound)));
+}
+
+#[test]
+fn from_css_errors() {
+    assert!(matches!(Theme::from_css(".card { color: red; }"), Err(ThemeError::NotFound)));
+    assert!(matches!(
+        Theme::from_css("@theme { --color-brand: red; color red }"),
+        Err(ThemeError::Syntax { offset: 29, .. })
+    ));
+    // Unterminated blocks, including a trailing escape
+    assert!(matches!(
+        Theme::from_css(".card {} @theme { --color-a: red; \\"),
+        Err(ThemeError::Syntax { offset: 9, .. })
+    ));
+    assert!(matches!(Theme::from_css("@theme { --color-a: red;"), Err(ThemeError::Syntax { offset: 0, .. })));
+    assert!(matches!(Theme::from_css("@theme;"), Err(ThemeError::Syntax { offset: 0, .. })));
+    assert!(matches!(Theme::from_css_file("tests/fixtures/missing.css"), Err(ThemeError::Io(_))));
+}
+
+#[test]
+fn from_tailwind_config() {
+    let config = TailwindConfig::from_json_file("tests/fixtures/tailwind.config.json").unwrap();
+    assert_eq!(config.prefix, "tw-");
+    assert_eq!(config.separator, "_");
+    assert_eq!(config.theme.colors, ["white", "brand", "brand-500"].map(String::from).into());
+    assert_eq!(config.theme.radius, ["pill"].map(String::from).into());
+    assert!(config.theme.breakpoints.contains("tablet"));
+    assert!(config.theme.font_size.contains("display"));
+    assert!(config.theme.shadow.contains("elevated"));
+    assert!(config.theme.spacing.contains("gutter"));
+
+    let merger = config.into_merger();
+    assert_eq!(merger.options().prefix, "tw-");
+    assert_eq!(merger.options().separator, "_");
+    assert_eq!(
+        merger.merge(&["hover_tw-text-lg hover_tw-text-display tw-shadow-lg tw-shadow-elevated"]),
+        "hover_tw-text-display tw-shadow-elevated"
+    );
+}
+
+#[test]
+fn from_tailwind_config_defaults() {
+    let merger = TailwindConfig::from_json("{}").unwrap().into_merger();
+    assert_eq!(merger.options().prefix, "");
+    assert_eq!(merger.options().separator, ":");
+
+    assert!(matches!(TailwindConfig::from_json("[]"), Err(ThemeError::NotFound)));
+    assert!(matches!(TailwindConfig::from_json(r#"{"prefix": tw}"#), Err(ThemeError::Syntax { .. })));
+    assert!(matches!(TailwindConfig::from_json(r#"{"prefix": "tw-""#), Err(ThemeError::Syntax { offset: 16, .. })));
+}